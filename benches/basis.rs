@@ -0,0 +1,91 @@
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use std::hint::black_box;
+
+use geometrica::euclidean::*;
+use geometrica::linear::LinearMap;
+use geometrica::linear::basis::{LinearBasis, OrthonormalLinearBasis};
+use nalgebra::Vector3;
+
+type V3 = Vector<U3>;
+
+fn skewed() -> LinearBasis<V3> {
+    let e = OrthonormalLinearBasis::<V3> {
+        _from_origin: Rotation3D::identity(),
+    };
+    let map =
+        LinearMap::<V3, V3>::from_local(&e, &e, &[2.0, 1.0, 0.0, 0.0, 1.0, 0.5, 0.3, 0.0, 1.0]);
+    LinearBasis {
+        _from_origin: map.to_group().unwrap(),
+    }
+}
+
+fn components(count: usize) -> Vec<[f64; 3]> {
+    (0..count)
+        .map(|i| {
+            let x = i as f64;
+            [x.sin(), x.cos(), 0.5 * x]
+        })
+        .collect()
+}
+
+fn bulk_conversions(c: &mut Criterion) {
+    let basis = skewed();
+    let mut group = c.benchmark_group("linear_basis");
+    for count in [1_000, 100_000] {
+        let local = components(count);
+        let vectors: Vec<V3> = local
+            .iter()
+            .map(|c| basis.from_local(&(*c).into()))
+            .collect();
+        group.throughput(Throughput::Elements(count as u64));
+
+        group.bench_with_input(BenchmarkId::new("from_local", count), &local, |b, local| {
+            b.iter(|| {
+                for c in local {
+                    black_box(basis.from_local(&(*c).into()));
+                }
+            })
+        });
+        // Baseline without the cached inverse, inverting the component map on every conversion.
+        // The matrix goes through `black_box` so that the inversion is not hoisted out of the loop.
+        let matrix = *LinearMap::from(basis._from_origin.clone())._get_raw();
+        group.bench_with_input(
+            BenchmarkId::new("from_local_uncached", count),
+            &local,
+            |b, local| {
+                b.iter(|| {
+                    for c in local {
+                        let inverse = black_box(matrix).try_inverse().unwrap();
+                        black_box(V3::_from_raw(inverse * Vector3::from(*c)));
+                    }
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("to_local", count),
+            &vectors,
+            |b, vectors| {
+                b.iter(|| {
+                    for v in vectors {
+                        black_box(basis.to_local(v));
+                    }
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("round_trip", count),
+            &vectors,
+            |b, vectors| {
+                b.iter(|| {
+                    for v in vectors {
+                        black_box(basis.from_local(&basis.to_local(v)));
+                    }
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bulk_conversions);
+criterion_main!(benches);
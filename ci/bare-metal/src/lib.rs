@@ -0,0 +1,39 @@
+#![no_std]
+
+use core::panic::PanicInfo;
+
+use geometrica::euclidean::*;
+use geometrica::kinematics::Kinematics;
+use geometrica::lie::{GroupAction, LieAlgebra};
+use geometrica::linear::basis::OrthonormalLinearBasis;
+
+#[panic_handler]
+fn panic(_: &PanicInfo) -> ! {
+    loop {}
+}
+
+// One step of an attitude controller: rotate a body axis, express it in a body frame and read
+// the pose of a stationary vehicle
+#[unsafe(no_mangle)]
+pub extern "C" fn attitude_step(axis: &[f64; 3], angle: f64, out: &mut [f64; 3]) {
+    let rotation = Rotation3D::from_axis_angle(&Vector::_from_raw((*axis).into()), angle);
+    let body = OrthonormalLinearBasis {
+        _from_origin: rotation.clone(),
+    };
+    let x = Vector::<U3>::_from_raw([1.0, 0.0, 0.0].into());
+    let components = body.to_local(&rotation.act_on(&x));
+
+    let vehicle = Kinematics::new(
+        Torsor {
+            _from_origin: Motion3D::identity(),
+        },
+        LieAlgebra::zero(),
+    );
+    let position = GroupAction::<EuclideanSpace<U3>>::act_on(
+        &vehicle.point._from_origin,
+        &EuclideanSpace {
+            _from_origin: Vector::_from_raw(components.into_array().into()),
+        },
+    );
+    out.copy_from_slice(position._from_origin._get_raw().as_slice());
+}
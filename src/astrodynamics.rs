@@ -1,19 +1,43 @@
+use nalgebra::convert;
+
 use crate::euclidean::*;
 use crate::kinematics::*;
 
-pub type ECI<K = f64> = OrthonormalAffineFrame<3, EuclideanSpace<3, K>>;
+pub mod geodesy;
+
+pub use geodesy::{Ellipsoid, GeodeticChart};
+
+pub type ECI<K = f64> = Torsor<Motion3D<K>>;
 
 impl<K: Scalar> ECI<K> {
     pub fn as_reference() -> Self {
-        EuclideanSpace::<3, K>::reference_frame()
+        Torsor {
+            _from_origin: Motion3D::identity(),
+        }
     }
 }
 
-pub type ECEF<K = f64> =
-    Kinematics<6, OrthonormalAffineFrame<3, EuclideanSpace<3, K>>, Motion3D<K>>;
+pub type ECEF<K = f64> = Kinematics<Motion3D<K>>;
+
+// Rotation rate of the Earth relative to ECI in rad/s, as defined for WGS 84
+pub const EARTH_ROTATION_RATE: f64 = 7.292_115e-5;
 
 impl<K: Scalar> ECEF<K> {
+    // ECEF at `t` seconds after its axes coincide with those of ECI
     pub fn at_time(t: K) -> Self {
-        !todo!("Implement ECEF at time {}", t);
+        earth_rotating_frame(t).kinematics()
     }
 }
+
+// ECEF as a frame rotating about the z axis of ECI
+pub fn earth_rotating_frame<K: Scalar>(t: K) -> MovingFrame<K> {
+    let z = Vector::_from_raw([K::zero(), K::zero(), K::one()].into());
+    let rate: K = convert(EARTH_ROTATION_RATE);
+    let axes = Torsor {
+        _from_origin: Rotation3D::from_axis_angle(&z, -rate * t),
+    };
+    let origin = Torsor {
+        _from_origin: Vector::zero(),
+    };
+    MovingFrame::rotating(origin, axes, &z * rate)
+}
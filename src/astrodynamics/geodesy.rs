@@ -0,0 +1,217 @@
+use generic_array::GenericArray;
+use nalgebra::{Matrix3, Rotation3, U3, UnitQuaternion, Vector3, convert};
+
+use crate::astrodynamics::ECEF;
+use crate::euclidean::*;
+use crate::lie::GroupAction;
+use crate::linear::SpecialOrthogonalGroup;
+use crate::linear::basis::OrthonormalLinearBasis;
+use crate::manifold::Components;
+
+// Reference ellipsoid of revolution
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ellipsoid<K: Scalar = f64> {
+    semi_major_axis: K,
+    flattening: K,
+}
+
+impl<K: Scalar> Ellipsoid<K> {
+    pub fn new(semi_major_axis: K, flattening: K) -> Self {
+        Self {
+            semi_major_axis,
+            flattening,
+        }
+    }
+
+    pub fn from_inverse_flattening(semi_major_axis: K, inverse_flattening: K) -> Self {
+        Self::new(semi_major_axis, K::one() / inverse_flattening)
+    }
+
+    pub fn from_axes(semi_major_axis: K, semi_minor_axis: K) -> Self {
+        Self::new(
+            semi_major_axis,
+            (semi_major_axis - semi_minor_axis) / semi_major_axis,
+        )
+    }
+
+    pub fn wgs84() -> Self {
+        Self::from_inverse_flattening(convert(6_378_137.0), convert(298.257_223_563))
+    }
+
+    pub fn grs80() -> Self {
+        Self::from_inverse_flattening(convert(6_378_137.0), convert(298.257_222_101))
+    }
+
+    pub fn semi_major_axis(&self) -> K {
+        self.semi_major_axis
+    }
+
+    pub fn semi_minor_axis(&self) -> K {
+        self.semi_major_axis * (K::one() - self.flattening)
+    }
+
+    pub fn flattening(&self) -> K {
+        self.flattening
+    }
+
+    // e^2 = f (2 - f)
+    pub fn eccentricity_squared(&self) -> K {
+        self.flattening * (convert::<f64, K>(2.0) - self.flattening)
+    }
+
+    // e'^2 = e^2 / (1 - e^2)
+    pub fn second_eccentricity_squared(&self) -> K {
+        let e2 = self.eccentricity_squared();
+        e2 / (K::one() - e2)
+    }
+
+    // Radius of curvature in the prime vertical, N(phi)
+    pub fn prime_vertical_radius(&self, latitude: K) -> K {
+        let sin = latitude.sin();
+        self.semi_major_axis / (K::one() - self.eccentricity_squared() * sin * sin).sqrt()
+    }
+
+    // Geocentric latitude of the surface point at the given geodetic latitude
+    pub fn geocentric_latitude(&self, geodetic_latitude: K) -> K {
+        ((K::one() - self.eccentricity_squared()) * geodetic_latitude.sin())
+            .atan2(geodetic_latitude.cos())
+    }
+
+    // [latitude, longitude, height] -> [x, y, z]
+    pub fn to_cartesian(
+        &self,
+        geodetic: &Components<EuclideanSpace<U3, K>>,
+    ) -> Components<EuclideanSpace<U3, K>> {
+        let (latitude, longitude, height) = (geodetic[0], geodetic[1], geodetic[2]);
+        let n = self.prime_vertical_radius(latitude);
+        let e2 = self.eccentricity_squared();
+        GenericArray::from_array([
+            (n + height) * latitude.cos() * longitude.cos(),
+            (n + height) * latitude.cos() * longitude.sin(),
+            (n * (K::one() - e2) + height) * latitude.sin(),
+        ])
+    }
+
+    // [x, y, z] -> [latitude, longitude, height]
+    //
+    // Bowring's method, iterated from the parametric latitude of the point. Three iterations
+    // reach machine precision for any point outside the immediate vicinity of the centre, and
+    // the height formula stays well conditioned at the poles.
+    pub fn to_geodetic(
+        &self,
+        cartesian: &Components<EuclideanSpace<U3, K>>,
+    ) -> Components<EuclideanSpace<U3, K>> {
+        let (x, y, z) = (cartesian[0], cartesian[1], cartesian[2]);
+        let a = self.semi_major_axis;
+        let b = self.semi_minor_axis();
+        let e2 = self.eccentricity_squared();
+        let ep2 = self.second_eccentricity_squared();
+
+        let p = (x * x + y * y).sqrt();
+        let longitude = y.atan2(x);
+
+        let mut beta = (a * z).atan2(b * p);
+        let mut latitude = beta;
+        for _ in 0..3 {
+            let (sin_beta, cos_beta) = beta.sin_cos();
+            latitude = (z + ep2 * b * sin_beta * sin_beta * sin_beta)
+                .atan2(p - e2 * a * cos_beta * cos_beta * cos_beta);
+            beta = ((K::one() - self.flattening) * latitude.sin()).atan2(latitude.cos());
+        }
+
+        let (sin, cos) = latitude.sin_cos();
+        let height = p * cos + z * sin - a * (K::one() - e2 * sin * sin).sqrt();
+        GenericArray::from_array([latitude, longitude, height])
+    }
+}
+
+// Geodetic coordinates [latitude, longitude, height] on an ECEF frame
+
+#[derive(Clone, Debug)]
+pub struct GeodeticChart<K: Scalar = f64> {
+    pub ellipsoid: Ellipsoid<K>,
+    pub frame: Torsor<Motion3D<K>>,
+}
+
+impl<K: Scalar> GeodeticChart<K> {
+    pub fn new(ellipsoid: Ellipsoid<K>, ecef: &ECEF<K>) -> Self {
+        Self {
+            ellipsoid,
+            frame: ecef.point.clone(),
+        }
+    }
+
+    pub fn wgs84(ecef: &ECEF<K>) -> Self {
+        Self::new(Ellipsoid::wgs84(), ecef)
+    }
+
+    pub fn geocentric_latitude(&self, point: &EuclideanSpace<U3, K>) -> K {
+        let xyz = self.frame._from_origin.act_on(point)._from_origin;
+        let raw = xyz._get_raw();
+        raw[2].atan2((raw[0] * raw[0] + raw[1] * raw[1]).sqrt())
+    }
+
+    // East-north-up basis of the tangent space at the point
+    pub fn enu_basis(
+        &self,
+        point: &EuclideanSpace<U3, K>,
+    ) -> OrthonormalLinearBasis<Vector<U3, K>> {
+        let (east, north, up) = self.local_axes(point);
+        self.local_basis(Matrix3::from_rows(&[
+            east.transpose(),
+            north.transpose(),
+            up.transpose(),
+        ]))
+    }
+
+    // North-east-down basis of the tangent space at the point
+    pub fn ned_basis(
+        &self,
+        point: &EuclideanSpace<U3, K>,
+    ) -> OrthonormalLinearBasis<Vector<U3, K>> {
+        let (east, north, up) = self.local_axes(point);
+        self.local_basis(Matrix3::from_rows(&[
+            north.transpose(),
+            east.transpose(),
+            -up.transpose(),
+        ]))
+    }
+
+    // East, north and up directions in ECEF components
+    fn local_axes(&self, point: &EuclideanSpace<U3, K>) -> (Vector3<K>, Vector3<K>, Vector3<K>) {
+        let geodetic = self.to_local(point);
+        let (sin_lat, cos_lat) = geodetic[0].sin_cos();
+        let (sin_lon, cos_lon) = geodetic[1].sin_cos();
+        (
+            Vector3::new(-sin_lon, cos_lon, K::zero()),
+            Vector3::new(-sin_lat * cos_lon, -sin_lat * sin_lon, cos_lat),
+            Vector3::new(cos_lat * cos_lon, cos_lat * sin_lon, sin_lat),
+        )
+    }
+
+    // `rows` maps ECEF components to local components
+    fn local_basis(&self, rows: Matrix3<K>) -> OrthonormalLinearBasis<Vector<U3, K>> {
+        let local = UnitQuaternion::from_rotation_matrix(&Rotation3::from_matrix_unchecked(rows));
+        Torsor {
+            _from_origin: SpecialOrthogonalGroup {
+                repr: local * self.frame._from_origin.repr.rotation(),
+            },
+        }
+    }
+}
+
+impl<K: Scalar> Chart<EuclideanSpace<U3, K>> for GeodeticChart<K> {
+    fn to_local(&self, point: &EuclideanSpace<U3, K>) -> Components<EuclideanSpace<U3, K>> {
+        let xyz = self.frame._from_origin.act_on(point)._from_origin;
+        self.ellipsoid
+            .to_geodetic(GenericArray::from_slice(xyz._get_raw().as_slice()))
+    }
+
+    fn from_local(&self, components: &Components<EuclideanSpace<U3, K>>) -> EuclideanSpace<U3, K> {
+        let xyz = self.ellipsoid.to_cartesian(components);
+        self.frame._from_origin.inverse().act_on(&Torsor {
+            _from_origin: Vector::_from_raw(Vector3::from_column_slice(xyz.as_slice())),
+        })
+    }
+}
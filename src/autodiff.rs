@@ -0,0 +1,645 @@
+use core::fmt::{self, Display};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use nalgebra::{ComplexField, DefaultAllocator, DimName, OMatrix, OVector, RealField};
+use nalgebra::{allocator::Allocator, convert};
+use num_traits::{FromPrimitive, Num, One, Signed, Zero};
+use simba::scalar::{Field, SubsetOf, SupersetOf};
+use simba::simd::SimdValue;
+
+use crate::manifold::Scalar;
+
+// Dual number a + b ε with ε^2 = 0, carrying a value and its first derivative
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Dual<K: Scalar = f64> {
+    re: K,
+    eps: K,
+}
+
+// Hyper-dual number a + b ε1 + c ε2 + d ε1 ε2, carrying first and mixed second derivatives
+pub type HyperDual<K = f64> = Dual<Dual<K>>;
+
+impl<K: Scalar> Dual<K> {
+    pub fn new(value: K, derivative: K) -> Self {
+        Self {
+            re: value,
+            eps: derivative,
+        }
+    }
+
+    pub fn constant(value: K) -> Self {
+        Self::new(value, K::zero())
+    }
+
+    pub fn variable(value: K) -> Self {
+        Self::new(value, K::one())
+    }
+
+    pub fn value(&self) -> K {
+        self.re
+    }
+
+    pub fn derivative(&self) -> K {
+        self.eps
+    }
+
+    // f(a + b ε) = f(a) + f'(a) b ε
+    fn chain(self, value: K, derivative: K) -> Self {
+        Self::new(value, derivative * self.eps)
+    }
+}
+
+// Derivative of a scalar function
+pub fn derivative<K: Scalar>(f: impl Fn(Dual<K>) -> Dual<K>, x: K) -> K {
+    f(Dual::variable(x)).eps
+}
+
+// Second derivative of a scalar function
+pub fn second_derivative<K: Scalar>(f: impl Fn(HyperDual<K>) -> HyperDual<K>, x: K) -> K {
+    f(Dual::new(Dual::variable(x), Dual::one())).eps.eps
+}
+
+// Jacobian of a vector function, one forward pass per column
+pub fn jacobian<K: Scalar, N: DimName, M: DimName>(
+    f: impl Fn(&OVector<Dual<K>, N>) -> OVector<Dual<K>, M>,
+    x: &OVector<K, N>,
+) -> OMatrix<K, M, N>
+where
+    DefaultAllocator: Allocator<N> + Allocator<M> + Allocator<M, N>,
+{
+    let mut jacobian = OMatrix::<K, M, N>::zeros();
+    for j in 0..N::dim() {
+        let seeded = OVector::<Dual<K>, N>::from_fn(|i, _| {
+            Dual::new(x[i], if i == j { K::one() } else { K::zero() })
+        });
+        let column = f(&seeded).map(|y| y.eps);
+        jacobian.set_column(j, &column);
+    }
+    jacobian
+}
+
+// Hessian of a scalar function, one forward pass per entry of the upper triangle
+pub fn hessian<K: Scalar, N: DimName>(
+    f: impl Fn(&OVector<HyperDual<K>, N>) -> HyperDual<K>,
+    x: &OVector<K, N>,
+) -> OMatrix<K, N, N>
+where
+    DefaultAllocator: Allocator<N> + Allocator<N, N>,
+{
+    let mut hessian = OMatrix::<K, N, N>::zeros();
+    for j in 0..N::dim() {
+        for k in j..N::dim() {
+            let seeded = OVector::<HyperDual<K>, N>::from_fn(|i, _| {
+                let e1 = if i == j { K::one() } else { K::zero() };
+                let e2 = if i == k { K::one() } else { K::zero() };
+                Dual::new(Dual::new(x[i], e1), Dual::constant(e2))
+            });
+            let value = f(&seeded).eps.eps;
+            hessian[(j, k)] = value;
+            hessian[(k, j)] = value;
+        }
+    }
+    hessian
+}
+
+// Comparisons only look at the value, so that branches in generic code follow the primal path
+
+impl<K: Scalar> PartialEq for Dual<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.re == other.re
+    }
+}
+
+impl<K: Scalar> PartialOrd for Dual<K> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.re.partial_cmp(&other.re)
+    }
+}
+
+impl<K: Scalar> Display for Dual<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} + {}ε", self.re, self.eps)
+    }
+}
+
+// Arithmetic
+
+impl<K: Scalar> Neg for Dual<K> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.eps)
+    }
+}
+
+impl<K: Scalar> Add for Dual<K> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self::new(self.re + other.re, self.eps + other.eps)
+    }
+}
+
+impl<K: Scalar> Sub for Dual<K> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.re - other.re, self.eps - other.eps)
+    }
+}
+
+impl<K: Scalar> Mul for Dual<K> {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.re * other.re,
+            self.eps * other.re + self.re * other.eps,
+        )
+    }
+}
+
+impl<K: Scalar> Div for Dual<K> {
+    type Output = Self;
+    fn div(self, other: Self) -> Self {
+        Self::new(
+            self.re / other.re,
+            (self.eps * other.re - self.re * other.eps) / (other.re * other.re),
+        )
+    }
+}
+
+impl<K: Scalar> Rem for Dual<K> {
+    type Output = Self;
+    fn rem(self, other: Self) -> Self {
+        Self::new(
+            self.re % other.re,
+            self.eps - (self.re / other.re).trunc() * other.eps,
+        )
+    }
+}
+
+macro_rules! impl_assign_op {
+    ($($Trait:ident, $method:ident, $op:tt);*) => {$(
+        impl<K: Scalar> $Trait for Dual<K> {
+            fn $method(&mut self, other: Self) {
+                *self = *self $op other;
+            }
+        }
+    )*};
+}
+
+impl_assign_op!(
+    AddAssign, add_assign, +;
+    SubAssign, sub_assign, -;
+    MulAssign, mul_assign, *;
+    DivAssign, div_assign, /;
+    RemAssign, rem_assign, %
+);
+
+// num-traits
+
+impl<K: Scalar> Zero for Dual<K> {
+    fn zero() -> Self {
+        Self::constant(K::zero())
+    }
+    fn is_zero(&self) -> bool {
+        self.re.is_zero()
+    }
+}
+
+impl<K: Scalar> One for Dual<K> {
+    fn one() -> Self {
+        Self::constant(K::one())
+    }
+}
+
+impl<K: Scalar> Num for Dual<K> {
+    type FromStrRadixErr = K::FromStrRadixErr;
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        K::from_str_radix(s, radix).map(Self::constant)
+    }
+}
+
+impl<K: Scalar> Signed for Dual<K> {
+    fn abs(&self) -> Self {
+        self.chain(Signed::abs(&self.re), Signed::signum(&self.re))
+    }
+    fn abs_sub(&self, other: &Self) -> Self {
+        if *self <= *other {
+            Self::zero()
+        } else {
+            *self - *other
+        }
+    }
+    fn signum(&self) -> Self {
+        Self::constant(Signed::signum(&self.re))
+    }
+    fn is_positive(&self) -> bool {
+        self.re.is_positive()
+    }
+    fn is_negative(&self) -> bool {
+        self.re.is_negative()
+    }
+}
+
+impl<K: Scalar> FromPrimitive for Dual<K> {
+    fn from_i64(n: i64) -> Option<Self> {
+        K::from_i64(n).map(Self::constant)
+    }
+    fn from_u64(n: u64) -> Option<Self> {
+        K::from_u64(n).map(Self::constant)
+    }
+    fn from_f64(n: f64) -> Option<Self> {
+        K::from_f64(n).map(Self::constant)
+    }
+}
+
+// approx
+
+impl<K: Scalar> AbsDiffEq for Dual<K> {
+    type Epsilon = Self;
+    fn default_epsilon() -> Self {
+        Self::constant(K::default_epsilon())
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self) -> bool {
+        self.re.abs_diff_eq(&other.re, epsilon.re)
+    }
+}
+
+impl<K: Scalar> RelativeEq for Dual<K> {
+    fn default_max_relative() -> Self {
+        Self::constant(K::default_max_relative())
+    }
+    fn relative_eq(&self, other: &Self, epsilon: Self, max_relative: Self) -> bool {
+        self.re.relative_eq(&other.re, epsilon.re, max_relative.re)
+    }
+}
+
+impl<K: Scalar> UlpsEq for Dual<K> {
+    fn default_max_ulps() -> u32 {
+        K::default_max_ulps()
+    }
+    fn ulps_eq(&self, other: &Self, epsilon: Self, max_ulps: u32) -> bool {
+        self.re.ulps_eq(&other.re, epsilon.re, max_ulps)
+    }
+}
+
+// simba
+
+impl<K: Scalar> SimdValue for Dual<K> {
+    const LANES: usize = 1;
+    type Element = Self;
+    type SimdBool = bool;
+
+    fn splat(val: Self) -> Self {
+        val
+    }
+    fn extract(&self, _: usize) -> Self {
+        *self
+    }
+    unsafe fn extract_unchecked(&self, _: usize) -> Self {
+        *self
+    }
+    fn replace(&mut self, _: usize, val: Self) {
+        *self = val
+    }
+    unsafe fn replace_unchecked(&mut self, _: usize, val: Self) {
+        *self = val
+    }
+    fn select(self, cond: bool, other: Self) -> Self {
+        if cond { self } else { other }
+    }
+}
+
+impl<K: Scalar> Field for Dual<K> {}
+
+impl<K: Scalar> SubsetOf<Dual<K>> for Dual<K> {
+    fn to_superset(&self) -> Self {
+        *self
+    }
+    fn from_superset_unchecked(element: &Self) -> Self {
+        *element
+    }
+    fn is_in_subset(_: &Self) -> bool {
+        true
+    }
+}
+
+macro_rules! impl_primitive_subset {
+    ($($T:ty),*) => {$(
+        impl<K: Scalar> SubsetOf<Dual<K>> for $T {
+            fn to_superset(&self) -> Dual<K> {
+                Dual::constant(K::from_subset(self))
+            }
+            fn from_superset_unchecked(element: &Dual<K>) -> Self {
+                <K as SupersetOf<$T>>::to_subset_unchecked(&element.re)
+            }
+            fn is_in_subset(element: &Dual<K>) -> bool {
+                element.eps.is_zero() && <K as SupersetOf<$T>>::is_in_subset(&element.re)
+            }
+        }
+    )*};
+}
+
+impl_primitive_subset!(f32, f64);
+
+impl<K: Scalar> ComplexField for Dual<K> {
+    type RealField = Self;
+
+    fn from_real(re: Self) -> Self {
+        re
+    }
+    fn real(self) -> Self {
+        self
+    }
+    fn imaginary(self) -> Self {
+        Self::zero()
+    }
+    fn modulus(self) -> Self {
+        Signed::abs(&self)
+    }
+    fn modulus_squared(self) -> Self {
+        self * self
+    }
+    fn argument(self) -> Self {
+        if self.re >= K::zero() {
+            Self::zero()
+        } else {
+            Self::pi()
+        }
+    }
+    fn norm1(self) -> Self {
+        Signed::abs(&self)
+    }
+    fn scale(self, factor: Self) -> Self {
+        self * factor
+    }
+    fn unscale(self, factor: Self) -> Self {
+        self / factor
+    }
+
+    fn floor(self) -> Self {
+        Self::constant(self.re.floor())
+    }
+    fn ceil(self) -> Self {
+        Self::constant(self.re.ceil())
+    }
+    fn round(self) -> Self {
+        Self::constant(self.re.round())
+    }
+    fn trunc(self) -> Self {
+        Self::constant(self.re.trunc())
+    }
+    fn fract(self) -> Self {
+        Self::new(self.re.fract(), self.eps)
+    }
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+
+    fn abs(self) -> Self {
+        Signed::abs(&self)
+    }
+    fn hypot(self, other: Self) -> Self {
+        let value = self.re.hypot(other.re);
+        let derivative = if value.is_zero() {
+            K::zero()
+        } else {
+            (self.re * self.eps + other.re * other.eps) / value
+        };
+        Self::new(value, derivative)
+    }
+    fn recip(self) -> Self {
+        self.chain(self.re.recip(), -(self.re * self.re).recip())
+    }
+    fn conjugate(self) -> Self {
+        self
+    }
+
+    fn sin(self) -> Self {
+        let (sin, cos) = self.re.sin_cos();
+        self.chain(sin, cos)
+    }
+    fn cos(self) -> Self {
+        let (sin, cos) = self.re.sin_cos();
+        self.chain(cos, -sin)
+    }
+    fn sin_cos(self) -> (Self, Self) {
+        let (sin, cos) = self.re.sin_cos();
+        (self.chain(sin, cos), self.chain(cos, -sin))
+    }
+    fn tan(self) -> Self {
+        let tan = self.re.tan();
+        self.chain(tan, K::one() + tan * tan)
+    }
+    fn asin(self) -> Self {
+        self.chain(
+            self.re.asin(),
+            (K::one() - self.re * self.re).sqrt().recip(),
+        )
+    }
+    fn acos(self) -> Self {
+        self.chain(
+            self.re.acos(),
+            -(K::one() - self.re * self.re).sqrt().recip(),
+        )
+    }
+    fn atan(self) -> Self {
+        self.chain(self.re.atan(), (K::one() + self.re * self.re).recip())
+    }
+    fn sinh(self) -> Self {
+        self.chain(self.re.sinh(), self.re.cosh())
+    }
+    fn cosh(self) -> Self {
+        self.chain(self.re.cosh(), self.re.sinh())
+    }
+    fn tanh(self) -> Self {
+        let tanh = self.re.tanh();
+        self.chain(tanh, K::one() - tanh * tanh)
+    }
+    fn asinh(self) -> Self {
+        self.chain(
+            self.re.asinh(),
+            (self.re * self.re + K::one()).sqrt().recip(),
+        )
+    }
+    fn acosh(self) -> Self {
+        self.chain(
+            self.re.acosh(),
+            (self.re * self.re - K::one()).sqrt().recip(),
+        )
+    }
+    fn atanh(self) -> Self {
+        self.chain(self.re.atanh(), (K::one() - self.re * self.re).recip())
+    }
+
+    fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+    fn log2(self) -> Self {
+        self.chain(self.re.log2(), (self.re * K::ln_2()).recip())
+    }
+    fn log10(self) -> Self {
+        self.chain(self.re.log10(), (self.re * K::ln_10()).recip())
+    }
+    fn ln(self) -> Self {
+        self.chain(self.re.ln(), self.re.recip())
+    }
+    fn ln_1p(self) -> Self {
+        self.chain(self.re.ln_1p(), (K::one() + self.re).recip())
+    }
+    fn sqrt(self) -> Self {
+        let sqrt = self.re.sqrt();
+        // The derivative is infinite at zero, which would make that of an argument constant to first
+        // order, such as the squared norm of a zero vector, 0 · ∞ = NaN. Like `hypot`, take zero
+        // there, and only there, so that higher-order parts elsewhere are still scaled
+        if self.re.is_zero() && self.eps.is_zero() {
+            return Self::new(sqrt, K::zero());
+        }
+        self.chain(sqrt, (sqrt + sqrt).recip())
+    }
+    fn exp(self) -> Self {
+        let exp = self.re.exp();
+        self.chain(exp, exp)
+    }
+    fn exp2(self) -> Self {
+        let exp2 = self.re.exp2();
+        self.chain(exp2, exp2 * K::ln_2())
+    }
+    fn exp_m1(self) -> Self {
+        self.chain(self.re.exp_m1(), self.re.exp())
+    }
+    fn powi(self, n: i32) -> Self {
+        if n == 0 {
+            return Self::one();
+        }
+        let n_k: K = convert(n as f64);
+        self.chain(self.re.powi(n), n_k * self.re.powi(n - 1))
+    }
+    fn powf(self, n: Self) -> Self {
+        let value = self.re.powf(n.re);
+        // ln 0 is singular, so drop the exponent term at zero, where x^n ln x tends to 0 for n > 0.
+        // Elsewhere ln |x| keeps it finite for negative bases, which only admit constant exponents
+        let derivative = if self.re.is_zero() {
+            n.re * self.re.powf(n.re - K::one()) * self.eps
+        } else {
+            value * (n.eps * self.re.abs().ln() + n.re * self.eps / self.re)
+        };
+        Self::new(value, derivative)
+    }
+    fn powc(self, n: Self) -> Self {
+        self.powf(n)
+    }
+    fn cbrt(self) -> Self {
+        let cbrt = self.re.cbrt();
+        let three: K = convert(3.0);
+        self.chain(cbrt, (three * cbrt * cbrt).recip())
+    }
+
+    fn is_finite(&self) -> bool {
+        self.re.is_finite() && self.eps.is_finite()
+    }
+    fn try_sqrt(self) -> Option<Self> {
+        if self.re >= K::zero() {
+            Some(self.sqrt())
+        } else {
+            None
+        }
+    }
+}
+
+impl<K: Scalar> RealField for Dual<K> {
+    fn is_sign_positive(&self) -> bool {
+        self.re.is_sign_positive()
+    }
+    fn is_sign_negative(&self) -> bool {
+        self.re.is_sign_negative()
+    }
+    fn copysign(self, sign: Self) -> Self {
+        if self.re.is_sign_positive() == sign.re.is_sign_positive() {
+            self
+        } else {
+            -self
+        }
+    }
+
+    fn max(self, other: Self) -> Self {
+        if self.re >= other.re { self } else { other }
+    }
+    fn min(self, other: Self) -> Self {
+        if self.re <= other.re { self } else { other }
+    }
+    fn clamp(self, min: Self, max: Self) -> Self {
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+    fn atan2(self, other: Self) -> Self {
+        let denominator = self.re * self.re + other.re * other.re;
+        let derivative = if denominator.is_zero() {
+            K::zero()
+        } else {
+            (self.eps * other.re - other.eps * self.re) / denominator
+        };
+        Self::new(self.re.atan2(other.re), derivative)
+    }
+
+    fn min_value() -> Option<Self> {
+        K::min_value().map(Self::constant)
+    }
+    fn max_value() -> Option<Self> {
+        K::max_value().map(Self::constant)
+    }
+
+    fn pi() -> Self {
+        Self::constant(K::pi())
+    }
+    fn two_pi() -> Self {
+        Self::constant(K::two_pi())
+    }
+    fn frac_pi_2() -> Self {
+        Self::constant(K::frac_pi_2())
+    }
+    fn frac_pi_3() -> Self {
+        Self::constant(K::frac_pi_3())
+    }
+    fn frac_pi_4() -> Self {
+        Self::constant(K::frac_pi_4())
+    }
+    fn frac_pi_6() -> Self {
+        Self::constant(K::frac_pi_6())
+    }
+    fn frac_pi_8() -> Self {
+        Self::constant(K::frac_pi_8())
+    }
+    fn frac_1_pi() -> Self {
+        Self::constant(K::frac_1_pi())
+    }
+    fn frac_2_pi() -> Self {
+        Self::constant(K::frac_2_pi())
+    }
+    fn frac_2_sqrt_pi() -> Self {
+        Self::constant(K::frac_2_sqrt_pi())
+    }
+
+    fn e() -> Self {
+        Self::constant(K::e())
+    }
+    fn log2_e() -> Self {
+        Self::constant(K::log2_e())
+    }
+    fn log10_e() -> Self {
+        Self::constant(K::log10_e())
+    }
+    fn ln_2() -> Self {
+        Self::constant(K::ln_2())
+    }
+    fn ln_10() -> Self {
+        Self::constant(K::ln_10())
+    }
+}
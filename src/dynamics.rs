@@ -0,0 +1,263 @@
+use core::cmp::Ordering;
+
+use approx::AbsDiffEq;
+use nalgebra::{ComplexField, Matrix3, Rotation3, U3, UnitQuaternion, Vector3, convert};
+
+use crate::error::{Error, Result};
+use crate::euclidean::motion::{join, split};
+use crate::euclidean::{EuclideanSpace, Motion3D, Vector};
+use crate::kinematics::{Convention, Kinematics};
+use crate::lie::{GroupAction, LieAlgebra, LieAlgebraDual, LieGroup, Torsor};
+use crate::linear::basis::{LinearChart, OrthonormalLinearBasis};
+use crate::linear::{AffineSpace, InnerProductSpace, LinearSpace, SpecialOrthogonalGroup};
+use crate::manifold::{One, Scalar, Zero, total_cmp};
+
+// Inertia tensor of a rigid body, a symmetric positive-definite bilinear form on a 3D inner
+// product space, stored in reference components
+
+#[derive(Clone, Debug)]
+pub struct Inertia<V: InnerProductSpace<Dim = U3>> {
+    raw: Matrix3<V::Field>,
+}
+
+impl<V: InnerProductSpace<Dim = U3>> Inertia<V> {
+    // Nine components in row-major order, which must be symmetric positive-definite
+    pub fn from_local(basis: &OrthonormalLinearBasis<V>, components: &[V::Field]) -> Result<Self> {
+        if components.len() != 9 {
+            return Err(Error::DimensionMismatch);
+        }
+        let local = Matrix3::from_row_slice(components);
+        let to_local = basis._to_local_matrix();
+        Self::try_from_raw(to_local.transpose() * local * to_local)
+    }
+
+    pub fn from_principal_moments(
+        basis: &OrthonormalLinearBasis<V>,
+        moments: [V::Field; 3],
+    ) -> Result<Self> {
+        let [a, b, c] = moments;
+        let zero = V::Field::zero();
+        Self::from_local(basis, &[a, zero, zero, zero, b, zero, zero, zero, c])
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_local(&self, basis: &OrthonormalLinearBasis<V>) -> alloc::vec::Vec<V::Field> {
+        let from_local = basis._from_local_matrix();
+        let local = from_local.transpose() * self.raw * from_local;
+        local.transpose().as_slice().to_vec()
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        Self::_from_raw(self.raw + other.raw)
+    }
+
+    // Inertia of the body after it is rotated by `rotation`, I' = R I R^T
+    pub fn rotate(&self, rotation: &SpecialOrthogonalGroup<V>) -> Self {
+        let matrix = rotation.repr.to_rotation_matrix().into_inner();
+        Self::_from_raw(matrix * self.raw * matrix.transpose())
+    }
+
+    // Parallel-axis theorem: with `self` taken about the center of mass, the inertia about
+    // `point` is I + m (|d|^2 1 - d d^T) where d is the offset of `point`
+    pub fn parallel_axis(
+        &self,
+        mass: V::Field,
+        center_of_mass: &AffineSpace<V>,
+        point: &AffineSpace<V>,
+    ) -> Self
+    where
+        V: LieGroup,
+    {
+        let d = point._from_origin._get_raw() - center_of_mass._from_origin._get_raw();
+        let shift = Matrix3::identity() * d.norm_squared() - d * d.transpose();
+        Self::_from_raw(self.raw + shift * mass)
+    }
+
+    // Principal moments in ascending order, with the orthonormal basis of principal axes
+    pub fn principal_axes(&self) -> ([V::Field; 3], OrthonormalLinearBasis<V>) {
+        let eigen = self.raw.symmetric_eigen();
+        let mut order = [0, 1, 2];
+        order.sort_unstable_by(|&i, &j| total_cmp(&eigen.eigenvalues[i], &eigen.eigenvalues[j]));
+
+        let mut axes = Matrix3::from_columns(&order.map(|i| eigen.eigenvectors.column(i)));
+        if axes.determinant() < V::Field::zero() {
+            axes.set_column(2, &-axes.column(2));
+        }
+        // The chart takes a vector to its components along the axes, so the rotation is A^T
+        let rotation = Rotation3::from_matrix_unchecked(axes.transpose());
+        let basis = OrthonormalLinearBasis {
+            _from_origin: SpecialOrthogonalGroup {
+                repr: UnitQuaternion::from_rotation_matrix(&rotation),
+            },
+        };
+        (order.map(|i| eigen.eigenvalues[i]), basis)
+    }
+
+    pub fn angular_momentum(&self, angular_velocity: &V) -> V {
+        V::_from_raw(self.raw * angular_velocity._get_raw())
+    }
+
+    pub fn kinetic_energy(&self, angular_velocity: &V) -> V::Field {
+        angular_velocity.dot(&self.angular_momentum(angular_velocity))
+            * convert::<f64, V::Field>(0.5)
+    }
+
+    fn try_from_raw(raw: Matrix3<V::Field>) -> Result<Self> {
+        let tolerance = V::Field::default_epsilon().sqrt() * (V::Field::one() + raw.amax());
+        if (raw - raw.transpose()).amax() > tolerance {
+            return Err(Error::NotSymmetric);
+        }
+        let symmetric = (raw + raw.transpose()) * convert::<f64, V::Field>(0.5);
+        symmetric.cholesky().ok_or(Error::NotPositive)?;
+        Ok(Self::_from_raw(symmetric))
+    }
+
+    pub fn _get_raw(&self) -> &Matrix3<V::Field> {
+        &self.raw
+    }
+    pub fn _from_raw(raw: Matrix3<V::Field>) -> Self {
+        Self { raw }
+    }
+}
+
+// Forces and torques acting on a rigid body, dual to its velocities: the torque about the
+// reference origin pairs with the angular velocity and the force with the velocity of the point
+// at the reference origin, giving the power
+
+pub type Wrench<K = f64> = LieAlgebraDual<Motion3D<K>>;
+
+impl<K: Scalar> Wrench<K> {
+    // Force acting along the line through `point`
+    pub fn from_force(force: &Vector<U3, K>, point: &EuclideanSpace<U3, K>) -> Self {
+        let (f, p) = (force._get_raw(), point._from_origin._get_raw());
+        Self::_from_raw(join(&p.cross(f), f))
+    }
+
+    pub fn from_torque(torque: &Vector<U3, K>) -> Self {
+        Self::_from_raw(join(torque._get_raw(), &Vector3::zeros()))
+    }
+
+    pub fn force(&self) -> Vector<U3, K> {
+        Vector::_from_raw(split(self._get_raw()).1)
+    }
+
+    pub fn torque_about(&self, point: &EuclideanSpace<U3, K>) -> Vector<U3, K> {
+        let torque = split(self._get_raw()).0;
+        let p = point._from_origin._get_raw();
+        Vector::_from_raw(torque - p.cross(self.force()._get_raw()))
+    }
+}
+
+// Mass distribution of a rigid body, with the center of mass and the inertia about it given by
+// their components in the body frame as if it were the reference. The dynamics below take the
+// body at the pose of a state, and give momenta and accelerations in the convention of the state.
+
+/// Bodies are only built by `RigidBody::try_new` and `RigidBody::new`, which check the mass:
+///
+/// ```compile_fail
+/// use geometrica::dynamics::RigidBody;
+/// fn weightless(body: RigidBody) -> RigidBody {
+///     RigidBody { mass: 0.0, ..body }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RigidBody<K: Scalar = f64> {
+    mass: K,
+    center_of_mass: EuclideanSpace<U3, K>,
+    inertia: Inertia<Vector<U3, K>>,
+}
+
+impl<K: Scalar> RigidBody<K> {
+    pub fn new(
+        mass: K,
+        center_of_mass: EuclideanSpace<U3, K>,
+        inertia: Inertia<Vector<U3, K>>,
+    ) -> Self {
+        Self::try_new(mass, center_of_mass, inertia).expect("Mass is not positive")
+    }
+
+    pub fn try_new(
+        mass: K,
+        center_of_mass: EuclideanSpace<U3, K>,
+        inertia: Inertia<Vector<U3, K>>,
+    ) -> Result<Self> {
+        // NaN is not comparable, and rejected as well
+        if mass.partial_cmp(&K::zero()) != Some(Ordering::Greater) {
+            return Err(Error::NotPositive);
+        }
+        Ok(Self {
+            mass,
+            center_of_mass,
+            inertia,
+        })
+    }
+
+    pub fn mass(&self) -> K {
+        self.mass
+    }
+
+    pub fn center_of_mass(&self) -> &EuclideanSpace<U3, K> {
+        &self.center_of_mass
+    }
+
+    pub fn inertia(&self) -> &Inertia<Vector<U3, K>> {
+        &self.inertia
+    }
+
+    pub fn kinetic_energy<C: Convention>(&self, state: &Kinematics<Motion3D<K>, C>) -> K {
+        let (inertia, w, _, v) = self.placed(state);
+        let half: K = convert(0.5);
+        (v.norm_squared() * self.mass + w.dot(&(inertia * w))) * half
+    }
+
+    // Angular momentum about the reference origin and linear momentum, whose pairing with the
+    // space velocity is twice the kinetic energy
+    pub fn momentum<C: Convention>(
+        &self,
+        state: &Kinematics<Motion3D<K>, C>,
+    ) -> LieAlgebraDual<Motion3D<K>> {
+        let (inertia, w, c, v) = self.placed(state);
+        let linear = v * self.mass;
+        LieAlgebraDual::_from_raw(join(&(inertia * w + c.cross(&linear)), &linear))
+    }
+
+    // Acceleration under the wrench by the Newton–Euler equations, m a = f for the center of mass
+    // and I dω/dt + ω × I ω = τ about it
+    pub fn acceleration<C: Convention>(
+        &self,
+        state: &Kinematics<Motion3D<K>, C>,
+        wrench: &Wrench<K>,
+    ) -> LieAlgebra<Motion3D<K>> {
+        let (inertia, w, c, v) = self.placed(state);
+        let center = Torsor {
+            _from_origin: Vector::_from_raw(c),
+        };
+        let torque = wrench.torque_about(&center)._get_raw() - w.cross(&(inertia * w));
+        let w_rate = inertia
+            .cholesky()
+            .expect("Inertia is positive-definite")
+            .solve(&torque);
+        let a = wrench.force()._get_raw() / self.mass;
+        // Rate of the velocity of the point at the reference origin, d/dt (v - ω × c)
+        let v_rate = a - w_rate.cross(&c) - w.cross(&v);
+        let space = LieAlgebra::_from_raw(join(&w_rate, &v_rate));
+        C::from_space(&state.point, &space)
+    }
+
+    // Inertia, angular velocity, center of mass and its velocity in the reference
+    fn placed<C: Convention>(
+        &self,
+        state: &Kinematics<Motion3D<K>, C>,
+    ) -> (Matrix3<K>, Vector3<K>, Vector3<K>, Vector3<K>) {
+        let pose = state.point._from_origin.inverse();
+        let center = GroupAction::<EuclideanSpace<U3, K>>::act_on(&pose, &self.center_of_mass);
+        let inertia = self.inertia.rotate(&pose.rotation());
+        let velocity = state.point_velocity(&center);
+        (
+            *inertia._get_raw(),
+            *state.angular_velocity()._get_raw(),
+            *center._from_origin._get_raw(),
+            *velocity._get_raw(),
+        )
+    }
+}
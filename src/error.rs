@@ -0,0 +1,68 @@
+use core::fmt;
+
+// Errors raised by fallible constructions on degenerate geometry
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    // A matrix or basis that is not invertible
+    Singular,
+    // Invertible, but too close to singular for its inverse to be trusted
+    IllConditioned,
+    ZeroVector,
+    // A NaN or infinite input
+    NotFinite,
+    // A quantity such as a mass, or a form such as an inertia tensor, that must be positive
+    NotPositive,
+    // Components of a form such as an inertia tensor that must be symmetric
+    NotSymmetric,
+    // No points or vectors where at least one is required
+    Empty,
+    // A vector lies in the span of the preceding ones
+    Degenerate,
+    // An axis index is out of range or given twice
+    InvalidAxis,
+    // A full set of basis vectors whose orientation is opposite to the requested one, the
+    // reference unless given
+    LeftHanded,
+    // A matrix that is not a rotation, or a homogeneous matrix that is not a rigid motion
+    NotRigid,
+    // Runtime-sized values whose dimensions do not agree
+    DimensionMismatch,
+    // A frame name that has not been registered
+    UnknownFrame,
+    DuplicateFrame,
+    // Reparenting a frame under itself or one of its descendants
+    FrameCycle,
+    // Frames with no common ancestor, so that no transform relates them
+    Disconnected,
+    // A root frame, which has no transform from a parent to update
+    NoParent,
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Singular => write!(f, "Matrix is not invertible"),
+            Self::IllConditioned => write!(f, "Matrix is ill-conditioned"),
+            Self::ZeroVector => write!(f, "Vector is zero"),
+            Self::NotFinite => write!(f, "Value is not finite"),
+            Self::NotPositive => write!(f, "Value is not positive"),
+            Self::NotSymmetric => write!(f, "Components are not symmetric"),
+            Self::Empty => write!(f, "At least one element is required"),
+            Self::Degenerate => write!(f, "Vectors are linearly dependent"),
+            Self::InvalidAxis => write!(f, "Axis index is out of range or repeated"),
+            Self::LeftHanded => write!(f, "Basis vectors are left-handed"),
+            Self::NotRigid => write!(f, "Matrix is not a rigid motion"),
+            Self::DimensionMismatch => write!(f, "Dimensions do not match"),
+            Self::UnknownFrame => write!(f, "Frame is not registered"),
+            Self::DuplicateFrame => write!(f, "Frame is already registered"),
+            Self::FrameCycle => write!(f, "Frames would form a cycle"),
+            Self::Disconnected => write!(f, "Frames are not connected"),
+            Self::NoParent => write!(f, "Frame has no parent"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
@@ -1,29 +1,38 @@
-use nalgebra::{DefaultAllocator, DimName, OVector, U1, U2, U3, allocator::Allocator};
+use nalgebra::{DefaultAllocator, Dim, DimName, OVector, U1, allocator::Allocator};
+pub use nalgebra::{Dyn, U2, U3};
 
+use crate::error::Result;
+use crate::lie::{Adjoint, ExponentialMap, GroupAction, LieAlgebra};
 use crate::linear::{SpecialEuclideanGroup, SpecialOrthogonalGroup};
-// use crate::linear::space::impl_vector_ops;
 
 pub use crate::lie::{LieGroup, Torsor};
-pub use crate::linear::{AffineSpace, InnerProductSpace, LinearSpace};
+pub use crate::linear::{
+    AffineFrame, AffineSpace, InnerProductSpace, LinearSpace, MetricSpace, OrthonormalAffineFrame,
+};
 pub use crate::manifold::{Chart, Manifold, Scalar};
 
+pub mod motion;
+
+pub use motion::Screw;
+
 // Vector space associated with Euclidean space
 
-pub struct Vector<N: DimName, K: Scalar = f64>
+#[derive(Clone, Debug)]
+pub struct Vector<N: Dim, K: Scalar = f64>
 where
     DefaultAllocator: Allocator<N, U1>,
 {
     raw: OVector<K, N>,
 }
 
-impl<N: DimName, K: Scalar> Manifold for Vector<N, K>
+impl<N: Dim, K: Scalar> Manifold for Vector<N, K>
 where
     DefaultAllocator: Allocator<N, U1>,
 {
     type Field = K;
     type Dim = N;
 }
-impl<N: DimName, K: Scalar> LinearSpace for Vector<N, K>
+impl<N: Dim, K: Scalar> LinearSpace for Vector<N, K>
 where
     DefaultAllocator: Allocator<N, U1>,
 {
@@ -37,7 +46,52 @@ where
         Self { raw }
     }
 }
-impl<N: DimName, K: Scalar> InnerProductSpace for Vector<N, K>
+impl<N: Dim, K: Scalar> LieGroup for Vector<N, K>
+where
+    DefaultAllocator: Allocator<N, U1>,
+{
+    fn identity() -> Self
+    where
+        N: DimName,
+    {
+        Self::zero()
+    }
+    fn multiply(&self, other: &Self) -> Self {
+        Self {
+            raw: &self.raw + &other.raw,
+        }
+    }
+    fn inverse(&self) -> Self {
+        Self { raw: -&self.raw }
+    }
+    fn try_multiply(&self, other: &Self) -> Result<Self> {
+        self.try_add(other)
+    }
+}
+impl<N: Dim, K: Scalar> ExponentialMap for Vector<N, K>
+where
+    DefaultAllocator: Allocator<N, U1>,
+{
+    fn exp(algebra: &LieAlgebra<Self>) -> Self {
+        Self {
+            raw: algebra._get_raw().clone(),
+        }
+    }
+}
+// Translations commute, so their adjoint representation is trivial
+impl<N: Dim, K: Scalar> Adjoint for Vector<N, K>
+where
+    DefaultAllocator: Allocator<N, U1>,
+{
+    fn adjoint(&self, algebra: &LieAlgebra<Self>) -> LieAlgebra<Self> {
+        algebra.clone()
+    }
+
+    fn bracket(a: &LieAlgebra<Self>, _: &LieAlgebra<Self>) -> LieAlgebra<Self> {
+        LieAlgebra::_from_raw(a._get_raw() * K::zero())
+    }
+}
+impl<N: Dim, K: Scalar> MetricSpace for Vector<N, K>
 where
     DefaultAllocator: Allocator<N, U1>,
 {
@@ -45,15 +99,17 @@ where
         self.raw.dot(&other.raw)
     }
 }
+impl<N: Dim, K: Scalar> InnerProductSpace for Vector<N, K> where DefaultAllocator: Allocator<N, U1> {}
 
-pub struct Covector<N: DimName, K: Scalar = f64>
+#[derive(Clone, Debug)]
+pub struct Covector<N: Dim, K: Scalar = f64>
 where
     DefaultAllocator: Allocator<N, U1>,
 {
     raw: OVector<K, N>,
 }
 
-impl<N: DimName, K: Scalar> Manifold for Covector<N, K>
+impl<N: Dim, K: Scalar> Manifold for Covector<N, K>
 where
     DefaultAllocator: Allocator<N, U1>,
 {
@@ -61,7 +117,7 @@ where
     type Dim = N;
 }
 
-impl<N: DimName, K: Scalar> LinearSpace for Covector<N, K>
+impl<N: Dim, K: Scalar> LinearSpace for Covector<N, K>
 where
     DefaultAllocator: Allocator<N, U1>,
 {
@@ -76,9 +132,111 @@ where
     }
 }
 
+// Arithmetic on vectors and covectors, for both owned values and references
+
+macro_rules! impl_vector_ops {
+    ($name:ident) => {
+        impl<N: Dim, K: Scalar> core::ops::Add for $name<N, K>
+        where
+            DefaultAllocator: Allocator<N, U1>,
+        {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self {
+                    raw: self.raw + rhs.raw,
+                }
+            }
+        }
+        impl<'a, N: Dim, K: Scalar> core::ops::Add for &'a $name<N, K>
+        where
+            DefaultAllocator: Allocator<N, U1>,
+        {
+            type Output = $name<N, K>;
+
+            fn add(self, rhs: Self) -> $name<N, K> {
+                $name {
+                    raw: &self.raw + &rhs.raw,
+                }
+            }
+        }
+        impl<N: Dim, K: Scalar> core::ops::Sub for $name<N, K>
+        where
+            DefaultAllocator: Allocator<N, U1>,
+        {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self {
+                    raw: self.raw - rhs.raw,
+                }
+            }
+        }
+        impl<'a, N: Dim, K: Scalar> core::ops::Sub for &'a $name<N, K>
+        where
+            DefaultAllocator: Allocator<N, U1>,
+        {
+            type Output = $name<N, K>;
+
+            fn sub(self, rhs: Self) -> $name<N, K> {
+                $name {
+                    raw: &self.raw - &rhs.raw,
+                }
+            }
+        }
+        impl<N: Dim, K: Scalar> core::ops::Neg for $name<N, K>
+        where
+            DefaultAllocator: Allocator<N, U1>,
+        {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { raw: -self.raw }
+            }
+        }
+        impl<'a, N: Dim, K: Scalar> core::ops::Neg for &'a $name<N, K>
+        where
+            DefaultAllocator: Allocator<N, U1>,
+        {
+            type Output = $name<N, K>;
+
+            fn neg(self) -> $name<N, K> {
+                $name { raw: -&self.raw }
+            }
+        }
+        impl<N: Dim, K: Scalar> core::ops::Mul<K> for $name<N, K>
+        where
+            DefaultAllocator: Allocator<N, U1>,
+        {
+            type Output = Self;
+
+            fn mul(self, rhs: K) -> Self {
+                Self {
+                    raw: self.raw * rhs,
+                }
+            }
+        }
+        impl<'a, N: Dim, K: Scalar> core::ops::Mul<K> for &'a $name<N, K>
+        where
+            DefaultAllocator: Allocator<N, U1>,
+        {
+            type Output = $name<N, K>;
+
+            fn mul(self, rhs: K) -> $name<N, K> {
+                $name {
+                    raw: &self.raw * rhs,
+                }
+            }
+        }
+    };
+}
+
+impl_vector_ops!(Vector);
+impl_vector_ops!(Covector);
+
 // Euclidean space
 
-pub type EuclideanSpace<N: DimName, K = f64> = AffineSpace<Vector<N, K>>;
+pub type EuclideanSpace<N, K = f64> = AffineSpace<Vector<N, K>>;
 
 // impl<const N: usize, K: Scalar> EuclideanSpace<N, K> {
 //     pub fn reference_frame() -> OrthonormalAffineFrame<N, Self> {
@@ -101,18 +259,28 @@ pub type EuclideanSpace<N: DimName, K = f64> = AffineSpace<Vector<N, K>>;
 
 // Translation group T(N)
 
-pub type Translation<N: DimName, K = f64> = Vector<N, K>;
+pub type Translation<N, K = f64> = Vector<N, K>;
+
+// Translations move points, and leave the displacements between them unchanged
+impl<N: Dim, K: Scalar> GroupAction<Vector<N, K>> for Translation<N, K>
+where
+    DefaultAllocator: Allocator<N, U1>,
+{
+    fn act_on(&self, vector: &Vector<N, K>) -> Vector<N, K> {
+        vector.clone()
+    }
+}
 
 // Rotation group or special orthogonal group SO(N)
 
 // pub trait Rotation<const N: usize, K: Scalar = f64>: Manifold<N> + LieGroup<N> {}
 
-pub type Rotation<N: DimName, K = f64> = SpecialOrthogonalGroup<Vector<N, K>>;
+pub type Rotation<N, K = f64> = SpecialOrthogonalGroup<Vector<N, K>>;
 pub type Rotation2D<K = f64> = Rotation<U2, K>;
 pub type Rotation3D<K = f64> = Rotation<U3, K>;
 
 // Motion group or special Euclidean group SE(N)
 
-pub type Motion<N: DimName, K = f64> = SpecialEuclideanGroup<Vector<N, K>>;
+pub type Motion<N, K = f64> = SpecialEuclideanGroup<Vector<N, K>>;
 pub type Motion2D<K = f64> = Motion<U2, K>;
 pub type Motion3D<K = f64> = Motion<U3, K>;
@@ -0,0 +1,280 @@
+use nalgebra::{
+    Const, DimMin, Matrix2, Matrix3, Matrix4, Rotation3, SMatrix, ToTypenum, U2, U3, Vector2,
+    Vector3, Vector4, Vector6, convert,
+};
+
+use crate::error::{Error, Result};
+use crate::euclidean::{
+    EuclideanSpace, LieGroup, LinearSpace, Motion2D, Motion3D, OrthonormalAffineFrame, Rotation2D,
+    Rotation3D, Scalar, Torsor, Vector,
+};
+use crate::lie::{Adjoint, LieAlgebra};
+use crate::linear::basis::OrthonormalLinearBasis;
+use crate::linear::group::euclidean::SERepr;
+
+// Constructions and decompositions of motions which depend on the dimension. Those which do not
+// are on `SpecialEuclideanGroup` itself.
+
+// Screw motion: a rotation by `angle` about the line through `point` along the unit `axis`,
+// together with a translation by `translation` along that line
+#[derive(Clone, Debug)]
+pub struct Screw<K: Scalar = f64> {
+    pub point: EuclideanSpace<U3, K>,
+    pub axis: Vector<U3, K>,
+    pub angle: K,
+    pub translation: K,
+}
+
+impl<K: Scalar> Screw<K> {
+    // Translation per radian, infinite for a pure translation
+    pub fn pitch(&self) -> K {
+        self.translation / self.angle
+    }
+}
+
+impl<K: Scalar> Motion3D<K> {
+    // Screw motion advancing by `pitch` along the axis per radian of rotation
+    pub fn from_screw(
+        point: &EuclideanSpace<U3, K>,
+        axis: &Vector<U3, K>,
+        angle: K,
+        pitch: K,
+    ) -> Self {
+        Self::try_from_screw(point, axis, angle, pitch).expect("Screw axis is zero")
+    }
+
+    pub fn try_from_screw(
+        point: &EuclideanSpace<U3, K>,
+        axis: &Vector<U3, K>,
+        angle: K,
+        pitch: K,
+    ) -> Result<Self> {
+        let rotation = Rotation3D::try_from_axis_angle(axis, angle)?;
+        let along = axis._get_raw().normalize() * (pitch * angle);
+        let translation = Self::from_translation(&Vector::_from_raw(along));
+        Ok(translation.multiply(&Self::from_rotation(&rotation, point)))
+    }
+
+    // Axis, angle and translation of the motion, with the angle in [0, π]. The point is the one
+    // on the axis closest to the reference origin. Pure translations have a zero angle and an axis
+    // along the translation, and the identity has no axis.
+    pub fn screw(&self) -> Option<Screw<K>> {
+        let (rotation, translation) = <U3 as SERepr<Vector<U3, K>>>::to_parts(&self.repr);
+        let t = *translation._get_raw();
+        let Some((axis, angle)) = Rotation3::from_matrix_unchecked(rotation).axis_angle() else {
+            let norm = t.norm();
+            return (norm > K::zero()).then(|| Screw {
+                point: Torsor {
+                    _from_origin: Vector::zero(),
+                },
+                axis: Vector::_from_raw(t / norm),
+                angle: K::zero(),
+                translation: norm,
+            });
+        };
+        let along = t.dot(&axis);
+        let normal = t - axis.into_inner() * along;
+        // The point c on the axis with c - R c equal to the normal part of the translation
+        let half: K = convert(0.5);
+        let cot = K::one() / (angle * half).tan();
+        let point = (normal + axis.cross(&normal) * cot) * half;
+        Some(Screw {
+            point: Torsor {
+                _from_origin: Vector::_from_raw(point),
+            },
+            axis: Vector::_from_raw(axis.into_inner()),
+            angle,
+            translation: along,
+        })
+    }
+
+    // Motion taking the reference frame to one at `eye` whose x axis points at `target` and
+    // whose z axis is as close to `up` as possible
+    pub fn look_at(
+        eye: &EuclideanSpace<U3, K>,
+        target: &EuclideanSpace<U3, K>,
+        up: &Vector<U3, K>,
+    ) -> Self {
+        Self::try_look_at(eye, target, up).expect("Target is at the eye or in the up direction")
+    }
+
+    pub fn try_look_at(
+        eye: &EuclideanSpace<U3, K>,
+        target: &EuclideanSpace<U3, K>,
+        up: &Vector<U3, K>,
+    ) -> Result<Self> {
+        let forward = target - eye;
+        let basis = OrthonormalLinearBasis::from_axes(&[(0, &forward), (2, up)])?;
+        Ok(Self::from_parts(
+            &basis._from_origin.inverse(),
+            &eye._from_origin,
+        ))
+    }
+
+    // Homogeneous matrix of the motion acting on components in the frame
+    pub fn to_homogeneous(&self, frame: &OrthonormalAffineFrame<Vector<U3, K>>) -> Matrix4<K> {
+        let local = in_frame(self, frame);
+        let (rotation, translation) = <U3 as SERepr<Vector<U3, K>>>::to_parts(&local.repr);
+        let mut matrix = rotation.to_homogeneous();
+        matrix
+            .fixed_view_mut::<3, 1>(0, 3)
+            .copy_from(translation._get_raw());
+        matrix
+    }
+
+    pub fn from_homogeneous(
+        frame: &OrthonormalAffineFrame<Vector<U3, K>>,
+        matrix: &Matrix4<K>,
+    ) -> Self {
+        Self::try_from_homogeneous(frame, matrix).expect("Matrix is not a rigid motion")
+    }
+
+    pub fn try_from_homogeneous(
+        frame: &OrthonormalAffineFrame<Vector<U3, K>>,
+        matrix: &Matrix4<K>,
+    ) -> Result<Self> {
+        let rotation: Matrix3<K> = matrix.fixed_view::<3, 3>(0, 0).into_owned();
+        let translation: Vector3<K> = matrix.fixed_view::<3, 1>(0, 3).into_owned();
+        let bottom = matrix.fixed_view::<1, 4>(3, 0).transpose();
+        if !is_rotation(&rotation) || bottom != Vector4::w() {
+            return Err(Error::NotRigid);
+        }
+        let local = Self {
+            repr: <U3 as SERepr<Vector<U3, K>>>::from_parts(
+                &rotation,
+                &Vector::_from_raw(translation),
+            ),
+        };
+        Ok(from_frame(&local, frame))
+    }
+}
+
+impl<K: Scalar> Motion2D<K> {
+    // Point left in place by the motion, which pure translations do not have
+    pub fn center(&self) -> Option<EuclideanSpace<U2, K>> {
+        let (rotation, translation) = <U2 as SERepr<Vector<U2, K>>>::to_parts(&self.repr);
+        let center = (Matrix2::identity() - rotation).try_inverse()? * translation._get_raw();
+        Some(Torsor {
+            _from_origin: Vector::_from_raw(center),
+        })
+    }
+
+    // Motion taking the reference frame to one at `eye` whose x axis points at `target`
+    pub fn look_at(eye: &EuclideanSpace<U2, K>, target: &EuclideanSpace<U2, K>) -> Self {
+        Self::try_look_at(eye, target).expect("Target is at the eye")
+    }
+
+    pub fn try_look_at(
+        eye: &EuclideanSpace<U2, K>,
+        target: &EuclideanSpace<U2, K>,
+    ) -> Result<Self> {
+        let forward = target - eye;
+        let raw = forward._get_raw();
+        if raw.norm() == K::zero() {
+            return Err(Error::ZeroVector);
+        }
+        let rotation = Rotation2D::from_angle(raw[1].atan2(raw[0]));
+        Ok(Self::from_parts(&rotation, &eye._from_origin))
+    }
+
+    // Homogeneous matrix of the motion acting on components in the frame
+    pub fn to_homogeneous(&self, frame: &OrthonormalAffineFrame<Vector<U2, K>>) -> Matrix3<K> {
+        let local = in_frame(self, frame);
+        let (rotation, translation) = <U2 as SERepr<Vector<U2, K>>>::to_parts(&local.repr);
+        let mut matrix = rotation.to_homogeneous();
+        matrix
+            .fixed_view_mut::<2, 1>(0, 2)
+            .copy_from(translation._get_raw());
+        matrix
+    }
+
+    pub fn from_homogeneous(
+        frame: &OrthonormalAffineFrame<Vector<U2, K>>,
+        matrix: &Matrix3<K>,
+    ) -> Self {
+        Self::try_from_homogeneous(frame, matrix).expect("Matrix is not a rigid motion")
+    }
+
+    pub fn try_from_homogeneous(
+        frame: &OrthonormalAffineFrame<Vector<U2, K>>,
+        matrix: &Matrix3<K>,
+    ) -> Result<Self> {
+        let rotation: Matrix2<K> = matrix.fixed_view::<2, 2>(0, 0).into_owned();
+        let translation: Vector2<K> = matrix.fixed_view::<2, 1>(0, 2).into_owned();
+        let bottom = matrix.fixed_view::<1, 3>(2, 0).transpose();
+        if !is_rotation(&rotation) || bottom != Vector3::z() {
+            return Err(Error::NotRigid);
+        }
+        let local = Self {
+            repr: <U2 as SERepr<Vector<U2, K>>>::from_parts(
+                &rotation,
+                &Vector::_from_raw(translation),
+            ),
+        };
+        Ok(from_frame(&local, frame))
+    }
+}
+
+// The angular part of an element of the algebra is a rotation vector, and for motions the linear
+// part is the velocity of the point at the reference origin
+
+impl<K: Scalar> Adjoint for Rotation3D<K> {
+    fn adjoint(&self, algebra: &LieAlgebra<Self>) -> LieAlgebra<Self> {
+        LieAlgebra::_from_raw(self.repr * algebra._get_raw())
+    }
+
+    fn bracket(a: &LieAlgebra<Self>, b: &LieAlgebra<Self>) -> LieAlgebra<Self> {
+        LieAlgebra::_from_raw(a._get_raw().cross(b._get_raw()))
+    }
+}
+
+impl<K: Scalar> Adjoint for Motion3D<K> {
+    // (R w, R v + t × R w)
+    fn adjoint(&self, algebra: &LieAlgebra<Self>) -> LieAlgebra<Self> {
+        let (rotation, translation) = <U3 as SERepr<Vector<U3, K>>>::to_parts(&self.repr);
+        let (w, v) = split(algebra._get_raw());
+        let w = rotation * w;
+        LieAlgebra::_from_raw(join(&w, &(rotation * v + translation._get_raw().cross(&w))))
+    }
+
+    // (w1 × w2, w1 × v2 - w2 × v1)
+    fn bracket(a: &LieAlgebra<Self>, b: &LieAlgebra<Self>) -> LieAlgebra<Self> {
+        let ((w1, v1), (w2, v2)) = (split(a._get_raw()), split(b._get_raw()));
+        LieAlgebra::_from_raw(join(&w1.cross(&w2), &(w1.cross(&v2) - w2.cross(&v1))))
+    }
+}
+
+// Angular and linear parts of the components of the Lie algebra of `Motion3D`, and of its dual
+pub(crate) fn split<K: Scalar>(raw: &Vector6<K>) -> (Vector3<K>, Vector3<K>) {
+    (
+        raw.fixed_rows::<3>(0).into_owned(),
+        raw.fixed_rows::<3>(3).into_owned(),
+    )
+}
+
+pub(crate) fn join<K: Scalar>(angular: &Vector3<K>, linear: &Vector3<K>) -> Vector6<K> {
+    let mut raw = Vector6::zeros();
+    raw.fixed_rows_mut::<3>(0).copy_from(angular);
+    raw.fixed_rows_mut::<3>(3).copy_from(linear);
+    raw
+}
+
+// The motion acting on components in the frame, and back
+fn in_frame<G: LieGroup>(motion: &G, frame: &Torsor<G>) -> G {
+    let frame = &frame._from_origin;
+    frame.multiply(motion).multiply(&frame.inverse())
+}
+
+fn from_frame<G: LieGroup>(local: &G, frame: &Torsor<G>) -> G {
+    let frame = &frame._from_origin;
+    frame.inverse().multiply(local).multiply(frame)
+}
+
+fn is_rotation<K: Scalar, const N: usize>(matrix: &SMatrix<K, N, N>) -> bool
+where
+    Const<N>: DimMin<Const<N>, Output = Const<N>> + ToTypenum,
+{
+    let tolerance = K::default_epsilon().sqrt();
+    let identity = SMatrix::<K, N, N>::identity();
+    (matrix.tr_mul(matrix) - identity).norm() <= tolerance && matrix.determinant() > K::zero()
+}
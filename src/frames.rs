@@ -0,0 +1,11 @@
+// Frames kept apart from the values expressed in them
+
+#[cfg(feature = "alloc")]
+pub mod graph;
+pub mod relative;
+pub mod tagged;
+
+#[cfg(feature = "alloc")]
+pub use graph::FrameGraph;
+pub use relative::{Lazy, Reference, Relative, Representation, Represented};
+pub use tagged::{Tagged, Transform};
@@ -0,0 +1,180 @@
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cell::RefCell;
+
+use nalgebra::DimName;
+
+use crate::error::{Error, Result};
+use crate::lie::{LieGroup, Torsor};
+
+// Tree of named frames, each given by the transform from the components of its parent to its own
+// components, as `_from_origin` is for a frame relative to the reference. Frames without a parent
+// are roots, and frames under different roots are not related to each other.
+//
+// Transforms between two frames are composed along the path through their lowest common
+// ancestor, and cached until a frame on that path is updated.
+
+#[derive(Clone, Debug)]
+struct Node<G> {
+    name: String,
+    parent: Option<(usize, G)>,
+}
+
+#[derive(Debug)]
+pub struct FrameGraph<G: LieGroup> {
+    frames: Vec<Node<G>>,
+    names: BTreeMap<String, usize>,
+    cache: RefCell<BTreeMap<(usize, usize), G>>,
+}
+
+impl<G: LieGroup + Clone> Default for FrameGraph<G>
+where
+    G::Dim: DimName,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G: LieGroup + Clone> FrameGraph<G>
+where
+    G::Dim: DimName,
+{
+    pub fn new() -> Self {
+        Self {
+            frames: Vec::new(),
+            names: BTreeMap::new(),
+            cache: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn add_root(&mut self, name: &str) -> Result<()> {
+        self.insert(name, None)
+    }
+
+    // Frame whose components are `transform` applied to those of `parent`
+    pub fn add(&mut self, name: &str, parent: &str, transform: G) -> Result<()> {
+        let parent = self.index(parent)?;
+        self.insert(name, Some((parent, transform)))
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.names.contains_key(name)
+    }
+
+    // Root of the tree the frame belongs to
+    pub fn root(&self, name: &str) -> Result<&str> {
+        let root = self.ancestors(self.index(name)?).last().unwrap();
+        Ok(&self.frames[root].name)
+    }
+
+    pub fn parent(&self, name: &str) -> Result<Option<&str>> {
+        let node = &self.frames[self.index(name)?];
+        Ok(node
+            .parent
+            .as_ref()
+            .map(|(i, _)| self.frames[*i].name.as_str()))
+    }
+
+    // Replaces the transform from the parent, such as the current pose of a moving body. Roots
+    // have no parent to be relative to.
+    pub fn update(&mut self, name: &str, transform: G) -> Result<()> {
+        let i = self.index(name)?;
+        let Some((_, current)) = &mut self.frames[i].parent else {
+            return Err(Error::NoParent);
+        };
+        *current = transform;
+        self.invalidate(i);
+        Ok(())
+    }
+
+    // Moves the frame, with everything below it, under another parent
+    pub fn reparent(&mut self, name: &str, parent: &str, transform: G) -> Result<()> {
+        let (i, parent) = (self.index(name)?, self.index(parent)?);
+        if self.is_ancestor(i, parent) {
+            return Err(Error::FrameCycle);
+        }
+        // Paths through the frame change in the old tree as well as in the new one
+        self.cache.borrow_mut().clear();
+        self.frames[i].parent = Some((parent, transform));
+        Ok(())
+    }
+
+    // Transform taking components in `from` to components in `to`
+    pub fn transform(&self, from: &str, to: &str) -> Result<G> {
+        let key = (self.index(from)?, self.index(to)?);
+        if let Some(g) = self.cache.borrow().get(&key) {
+            return Ok(g.clone());
+        }
+        let g = self.compose(key.0, key.1)?;
+        self.cache.borrow_mut().insert(key, g.clone());
+        Ok(g)
+    }
+
+    // The frame relative to its root, so that it can be used as a chart of the root's space
+    pub fn frame(&self, name: &str) -> Result<Torsor<G>> {
+        Ok(Torsor {
+            _from_origin: self.transform(self.root(name)?, name)?,
+        })
+    }
+
+    // Number of composed transforms currently cached
+    pub fn cached(&self) -> usize {
+        self.cache.borrow().len()
+    }
+
+    fn insert(&mut self, name: &str, parent: Option<(usize, G)>) -> Result<()> {
+        if self.contains(name) {
+            return Err(Error::DuplicateFrame);
+        }
+        self.names.insert(name.to_string(), self.frames.len());
+        self.frames.push(Node {
+            name: name.to_string(),
+            parent,
+        });
+        Ok(())
+    }
+
+    fn index(&self, name: &str) -> Result<usize> {
+        self.names.get(name).copied().ok_or(Error::UnknownFrame)
+    }
+
+    // The frame itself, then its parent and so on up to the root
+    fn ancestors(&self, i: usize) -> impl Iterator<Item = usize> + '_ {
+        core::iter::successors(Some(i), |j| {
+            self.frames[*j].parent.as_ref().map(|(p, _)| *p)
+        })
+    }
+
+    fn is_ancestor(&self, ancestor: usize, i: usize) -> bool {
+        self.ancestors(i).any(|j| j == ancestor)
+    }
+
+    // Transform from the components of `ancestor` to those of `i`
+    fn down_from(&self, ancestor: usize, i: usize) -> G {
+        self.ancestors(i)
+            .take_while(|j| *j != ancestor)
+            .fold(G::identity(), |g, j| {
+                g.multiply(&self.frames[j].parent.as_ref().unwrap().1)
+            })
+    }
+
+    fn compose(&self, from: usize, to: usize) -> Result<G> {
+        let lca = self
+            .ancestors(from)
+            .find(|a| self.is_ancestor(*a, to))
+            .ok_or(Error::Disconnected)?;
+        let down = self.down_from(lca, to);
+        let up = self.down_from(lca, from).inverse();
+        Ok(down.multiply(&up))
+    }
+
+    // Drops the cached transforms whose path uses the transform of frame `i`, that is those
+    // between a frame below `i` and a frame which is not
+    fn invalidate(&self, i: usize) {
+        self.cache
+            .borrow_mut()
+            .retain(|(from, to), _| self.is_ancestor(i, *from) == self.is_ancestor(i, *to));
+    }
+}
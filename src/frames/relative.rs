@@ -0,0 +1,222 @@
+use core::ops::{Add, Mul, Neg, Sub};
+
+use nalgebra::{DefaultAllocator, Dim, U1, allocator::Allocator};
+
+use crate::euclidean::{EuclideanSpace, Vector};
+use crate::lie::{GroupAction, LieGroup, Torsor};
+use crate::manifold::{Manifold, Scalar};
+
+// The crate otherwise keeps every value as its components in the single reference frame, which
+// loses precision when a frame is far from the reference origin, e.g. on the lunar surface with
+// ECI as the reference. A `Relative` value instead keeps the frame it was created in together
+// with its components there, and is only converted to another frame when asked to.
+//
+// `local` holds the components in the frame as if they were reference components, that is
+// `frame._from_origin.act_on(value)`.
+
+#[derive(Clone, Debug)]
+pub struct Relative<'a, G: LieGroup, M> {
+    frame: &'a Torsor<G>,
+    local: M,
+}
+
+impl<'a, G: LieGroup + Clone, M: Manifold + Clone> Relative<'a, G, M>
+where
+    G: GroupAction<M>,
+{
+    pub fn from_local(frame: &'a Torsor<G>, local: M) -> Self {
+        Self { frame, local }
+    }
+
+    pub fn from_reference(frame: &'a Torsor<G>, value: &M) -> Self {
+        Self {
+            frame,
+            local: frame._from_origin.act_on(value),
+        }
+    }
+
+    pub fn frame(&self) -> &'a Torsor<G> {
+        self.frame
+    }
+
+    pub fn local(&self) -> &M {
+        &self.local
+    }
+
+    pub fn to_reference(&self) -> M {
+        self.frame._from_origin.inverse().act_on(&self.local)
+    }
+
+    // The same value relative to another frame. The transform between the two frames is composed
+    // from their transforms from the reference, so it is only as precise as those: two nearby
+    // frames far from the reference origin lose the precision of their offset here. Use
+    // `express_via` with a transform known directly, such as one from a `FrameGraph`, instead.
+    pub fn express_in<'b>(&self, frame: &'b Torsor<G>) -> Relative<'b, G, M> {
+        if core::ptr::eq(self.frame, frame) {
+            return Relative {
+                frame,
+                local: self.local.clone(),
+            };
+        }
+        let transform = frame
+            ._from_origin
+            .multiply(&self.frame._from_origin.inverse());
+        self.express_via(frame, &transform)
+    }
+
+    // The same value relative to another frame, given the transform taking components in the
+    // frame of this value to components in the other one
+    pub fn express_via<'b>(&self, frame: &'b Torsor<G>, transform: &G) -> Relative<'b, G, M> {
+        Relative {
+            frame,
+            local: transform.act_on(&self.local),
+        }
+    }
+}
+
+// Arithmetic between relative vectors and points, which must be in the same frame. Operands in
+// different frames are rejected with a panic rather than converted implicitly, since the
+// conversion through the reference by `express_in` is what loses precision for nearby frames far
+// from the reference origin. Bring one operand into the frame of the other first, with
+// `express_via` given a transform known directly, or with `express_in` where that loss is
+// acceptable.
+
+fn check_frame<G: LieGroup>(frame: &Torsor<G>, other: &Torsor<G>) {
+    assert!(
+        core::ptr::eq(frame, other),
+        "Operands are in different frames"
+    );
+}
+
+impl<'a, G: LieGroup, N: Dim, K: Scalar> Add for &Relative<'a, G, Vector<N, K>>
+where
+    DefaultAllocator: Allocator<N, U1>,
+{
+    type Output = Relative<'a, G, Vector<N, K>>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        check_frame(self.frame, rhs.frame);
+        Relative {
+            frame: self.frame,
+            local: &self.local + &rhs.local,
+        }
+    }
+}
+
+impl<'a, G: LieGroup, N: Dim, K: Scalar> Sub for &Relative<'a, G, Vector<N, K>>
+where
+    DefaultAllocator: Allocator<N, U1>,
+{
+    type Output = Relative<'a, G, Vector<N, K>>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        check_frame(self.frame, rhs.frame);
+        Relative {
+            frame: self.frame,
+            local: &self.local - &rhs.local,
+        }
+    }
+}
+
+impl<'a, G: LieGroup, N: Dim, K: Scalar> Neg for &Relative<'a, G, Vector<N, K>>
+where
+    DefaultAllocator: Allocator<N, U1>,
+{
+    type Output = Relative<'a, G, Vector<N, K>>;
+
+    fn neg(self) -> Self::Output {
+        Relative {
+            frame: self.frame,
+            local: -&self.local,
+        }
+    }
+}
+
+impl<'a, G: LieGroup, N: Dim, K: Scalar> Mul<K> for &Relative<'a, G, Vector<N, K>>
+where
+    DefaultAllocator: Allocator<N, U1>,
+{
+    type Output = Relative<'a, G, Vector<N, K>>;
+
+    fn mul(self, rhs: K) -> Self::Output {
+        Relative {
+            frame: self.frame,
+            local: &self.local * rhs,
+        }
+    }
+}
+
+impl<'a, G: LieGroup, N: Dim, K: Scalar> Add<&Relative<'_, G, Vector<N, K>>>
+    for &Relative<'a, G, EuclideanSpace<N, K>>
+where
+    DefaultAllocator: Allocator<N, U1>,
+{
+    type Output = Relative<'a, G, EuclideanSpace<N, K>>;
+
+    fn add(self, rhs: &Relative<'_, G, Vector<N, K>>) -> Self::Output {
+        check_frame(self.frame, rhs.frame);
+        Relative {
+            frame: self.frame,
+            local: &self.local + &rhs.local,
+        }
+    }
+}
+
+impl<'a, G: LieGroup, N: Dim, K: Scalar> Sub for &Relative<'a, G, EuclideanSpace<N, K>>
+where
+    DefaultAllocator: Allocator<N, U1>,
+{
+    type Output = Relative<'a, G, Vector<N, K>>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        check_frame(self.frame, rhs.frame);
+        Relative {
+            frame: self.frame,
+            local: &self.local - &rhs.local,
+        }
+    }
+}
+
+// Choice between the two representations, so that code can be written once for both, e.g.
+// `<R as Representation<_, _>>::from_local(&frame, point)` with `R` either `Reference` or `Lazy`
+
+pub trait Representation<'a, G: LieGroup, M> {
+    type Value;
+
+    fn from_local(frame: &'a Torsor<G>, local: M) -> Self::Value;
+    fn to_reference(value: &Self::Value) -> M;
+}
+
+// Components in the reference frame, converted as soon as the value is created
+pub struct Reference;
+
+// Components in the frame the value was created in, converted only when needed
+pub struct Lazy;
+
+impl<'a, G: LieGroup + GroupAction<M>, M: Manifold + Clone> Representation<'a, G, M> for Reference {
+    type Value = M;
+
+    fn from_local(frame: &'a Torsor<G>, local: M) -> M {
+        frame._from_origin.inverse().act_on(&local)
+    }
+
+    fn to_reference(value: &M) -> M {
+        value.clone()
+    }
+}
+
+impl<'a, G: LieGroup + Clone + GroupAction<M> + 'a, M: Manifold + Clone> Representation<'a, G, M>
+    for Lazy
+{
+    type Value = Relative<'a, G, M>;
+
+    fn from_local(frame: &'a Torsor<G>, local: M) -> Self::Value {
+        Relative::from_local(frame, local)
+    }
+
+    fn to_reference(value: &Self::Value) -> M {
+        value.to_reference()
+    }
+}
+
+pub type Represented<'a, R, G, M> = <R as Representation<'a, G, M>>::Value;
@@ -0,0 +1,232 @@
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, Mul, Neg, Sub};
+
+use nalgebra::{DefaultAllocator, Dim, DimName, U1, allocator::Allocator};
+
+use crate::euclidean::{EuclideanSpace, Motion3D, Vector};
+use crate::frames::Relative;
+use crate::lie::{GroupAction, LieGroup, Torsor};
+use crate::manifold::{Manifold, Scalar};
+
+// Opt-in layer on top of the coordinate-free types for frames known at compile time, such as
+// the body, a sensor and the world. Frames are marker types, e.g. `struct Body;`, which are never
+// instantiated.
+//
+// A `Tagged<F, M>` holds the components of a value in the frame F as if they were reference
+// components, as `Relative::local` does, and a `Transform<From, To>` takes components in From to
+// components in To, as `_from_origin` does for a frame. Values and transforms can then only be
+// combined when their frames match. Both wrappers have the layout of the value they hold, so
+// that tagging and untagging cost nothing.
+//
+// The tags cannot tell a frame at two different times apart; values of a moving frame should be
+// kept coordinate-free or `Relative` to a frame value instead.
+
+#[repr(transparent)]
+pub struct Tagged<F, M> {
+    local: M,
+    _frame: PhantomData<fn() -> F>,
+}
+
+impl<F, M> Tagged<F, M> {
+    pub fn new(local: M) -> Self {
+        Self {
+            local,
+            _frame: PhantomData,
+        }
+    }
+
+    pub fn local(&self) -> &M {
+        &self.local
+    }
+
+    // The untyped value, which is the coordinate-free value itself when F is the reference
+    pub fn into_inner(self) -> M {
+        self.local
+    }
+
+    // The coordinate-free value, given the value of the frame F
+    pub fn relative_to<G>(self, frame: &Torsor<G>) -> Relative<'_, G, M>
+    where
+        G: LieGroup + Clone + GroupAction<M>,
+        M: Manifold + Clone,
+    {
+        Relative::from_local(frame, self.local)
+    }
+}
+
+impl<F, M: Clone> Clone for Tagged<F, M> {
+    fn clone(&self) -> Self {
+        Self::new(self.local.clone())
+    }
+}
+
+impl<F, M: Copy> Copy for Tagged<F, M> {}
+
+impl<F, M: fmt::Debug> fmt::Debug for Tagged<F, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tagged")
+            .field("frame", &core::any::type_name::<F>())
+            .field("local", &self.local)
+            .finish()
+    }
+}
+
+/// Composing transforms whose frames do not meet is rejected at compile time:
+///
+/// ```compile_fail
+/// use geometrica::euclidean::Motion3D;
+/// use geometrica::frames::Transform;
+/// use geometrica::lie::LieGroup;
+/// struct World;
+/// struct Body;
+/// struct Sensor;
+/// let body = Transform::<World, Body>::new(Motion3D::identity());
+/// let sensor = Transform::<Body, Sensor>::new(Motion3D::identity());
+/// let _ = body.multiply(&sensor);
+/// ```
+#[repr(transparent)]
+pub struct Transform<From, To, G = Motion3D> {
+    group: G,
+    _frames: PhantomData<fn(From) -> To>,
+}
+
+impl<From, To, G: LieGroup> Transform<From, To, G> {
+    pub fn new(group: G) -> Self {
+        Self {
+            group,
+            _frames: PhantomData,
+        }
+    }
+
+    // Transform from the reference, tagged as From, to the components of a frame
+    pub fn from_frame(frame: &Torsor<G>) -> Self
+    where
+        G: Clone,
+    {
+        Self::new(frame._from_origin.clone())
+    }
+
+    pub fn group(&self) -> &G {
+        &self.group
+    }
+
+    pub fn into_inner(self) -> G {
+        self.group
+    }
+
+    // `self` after `other`
+    pub fn multiply<A>(&self, other: &Transform<A, From, G>) -> Transform<A, To, G> {
+        Transform::new(self.group.multiply(&other.group))
+    }
+
+    pub fn inverse(&self) -> Transform<To, From, G> {
+        Transform::new(self.group.inverse())
+    }
+
+    pub fn act_on<M>(&self, value: &Tagged<From, M>) -> Tagged<To, M>
+    where
+        G: GroupAction<M>,
+        M: Manifold,
+    {
+        Tagged::new(self.group.act_on(&value.local))
+    }
+}
+
+impl<F, G: LieGroup> Transform<F, F, G>
+where
+    G::Dim: DimName,
+{
+    pub fn identity() -> Self {
+        Self::new(G::identity())
+    }
+}
+
+impl<From, To, G: Clone> Clone for Transform<From, To, G> {
+    fn clone(&self) -> Self {
+        Self {
+            group: self.group.clone(),
+            _frames: PhantomData,
+        }
+    }
+}
+
+impl<From, To, G: Copy> Copy for Transform<From, To, G> {}
+
+impl<From, To, G: fmt::Debug> fmt::Debug for Transform<From, To, G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Transform")
+            .field("from", &core::any::type_name::<From>())
+            .field("to", &core::any::type_name::<To>())
+            .field("group", &self.group)
+            .finish()
+    }
+}
+
+// Arithmetic between tagged vectors and points of the same frame
+
+impl<F, N: Dim, K: Scalar> Add for &Tagged<F, Vector<N, K>>
+where
+    DefaultAllocator: Allocator<N, U1>,
+{
+    type Output = Tagged<F, Vector<N, K>>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Tagged::new(&self.local + &rhs.local)
+    }
+}
+
+impl<F, N: Dim, K: Scalar> Sub for &Tagged<F, Vector<N, K>>
+where
+    DefaultAllocator: Allocator<N, U1>,
+{
+    type Output = Tagged<F, Vector<N, K>>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Tagged::new(&self.local - &rhs.local)
+    }
+}
+
+impl<F, N: Dim, K: Scalar> Neg for &Tagged<F, Vector<N, K>>
+where
+    DefaultAllocator: Allocator<N, U1>,
+{
+    type Output = Tagged<F, Vector<N, K>>;
+
+    fn neg(self) -> Self::Output {
+        Tagged::new(-&self.local)
+    }
+}
+
+impl<F, N: Dim, K: Scalar> Mul<K> for &Tagged<F, Vector<N, K>>
+where
+    DefaultAllocator: Allocator<N, U1>,
+{
+    type Output = Tagged<F, Vector<N, K>>;
+
+    fn mul(self, rhs: K) -> Self::Output {
+        Tagged::new(&self.local * rhs)
+    }
+}
+
+impl<F, N: Dim, K: Scalar> Add<&Tagged<F, Vector<N, K>>> for &Tagged<F, EuclideanSpace<N, K>>
+where
+    DefaultAllocator: Allocator<N, U1>,
+{
+    type Output = Tagged<F, EuclideanSpace<N, K>>;
+
+    fn add(self, rhs: &Tagged<F, Vector<N, K>>) -> Self::Output {
+        Tagged::new(&self.local + &rhs.local)
+    }
+}
+
+impl<F, N: Dim, K: Scalar> Sub for &Tagged<F, EuclideanSpace<N, K>>
+where
+    DefaultAllocator: Allocator<N, U1>,
+{
+    type Output = Tagged<F, Vector<N, K>>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Tagged::new(&self.local - &rhs.local)
+    }
+}
@@ -1,6 +1,13 @@
 // use crate::manifold::{LieGroup, Manifold, TangentBundle, TangentVector};
-use crate::lie::{LieAlgebra, LieGroup, Torsor};
-use nalgebra::{DefaultAllocator, allocator::Allocator};
+use crate::lie::{Adjoint, ExponentialMap, LieAlgebra, LieGroup, Torsor};
+use crate::manifold::Zero;
+use core::marker::PhantomData;
+use nalgebra::{DefaultAllocator, DimName, allocator::Allocator};
+
+pub mod moving;
+pub mod relative;
+
+pub use moving::{FrameTrajectory, MovingFrame};
 
 // pub struct Velocity<'a, const D: usize, G: LieGroup<D>> {
 //     position: &'a G,
@@ -14,35 +21,156 @@ pub trait HasAcceleration {
     type Acceleration;
 }
 
-pub struct Kinematics<G: LieGroup>
+// Which frame a velocity is held fixed in. A body velocity is seen from the moving frame and a
+// space velocity from the reference, so that a pose `g` taking the reference frame to the body
+// moves as `g exp(ξ t)` or `exp(ξ t) g` respectively. For motions, a body velocity is the angular
+// velocity and the velocity of the body origin in body components, and a space velocity is the
+// angular velocity and the velocity of the body point at the reference origin in reference
+// components.
+pub trait Convention {
+    // Pose after moving with the velocity for unit time
+    fn advance<G: ExponentialMap>(point: &Torsor<G>, velocity: &LieAlgebra<G>) -> Torsor<G>
+    where
+        DefaultAllocator: Allocator<G::Dim>;
+
+    // Space velocity or acceleration of a body at `point` given in this convention, and back
+    fn to_space<G: Adjoint>(point: &Torsor<G>, algebra: &LieAlgebra<G>) -> LieAlgebra<G>
+    where
+        DefaultAllocator: Allocator<G::Dim>;
+    fn from_space<G: Adjoint>(point: &Torsor<G>, algebra: &LieAlgebra<G>) -> LieAlgebra<G>
+    where
+        DefaultAllocator: Allocator<G::Dim>;
+}
+
+pub struct Body;
+pub struct Space;
+
+// `_from_origin` is the inverse of the pose taking the reference frame to the body
+
+impl Convention for Body {
+    fn advance<G: ExponentialMap>(point: &Torsor<G>, velocity: &LieAlgebra<G>) -> Torsor<G>
+    where
+        DefaultAllocator: Allocator<G::Dim>,
+    {
+        Torsor {
+            _from_origin: G::exp(velocity).inverse().multiply(&point._from_origin),
+        }
+    }
+
+    fn to_space<G: Adjoint>(point: &Torsor<G>, algebra: &LieAlgebra<G>) -> LieAlgebra<G>
+    where
+        DefaultAllocator: Allocator<G::Dim>,
+    {
+        algebra.from_frame(point)
+    }
+
+    fn from_space<G: Adjoint>(point: &Torsor<G>, algebra: &LieAlgebra<G>) -> LieAlgebra<G>
+    where
+        DefaultAllocator: Allocator<G::Dim>,
+    {
+        algebra.in_frame(point)
+    }
+}
+
+impl Convention for Space {
+    fn advance<G: ExponentialMap>(point: &Torsor<G>, velocity: &LieAlgebra<G>) -> Torsor<G>
+    where
+        DefaultAllocator: Allocator<G::Dim>,
+    {
+        Torsor {
+            _from_origin: point._from_origin.multiply(&G::exp(velocity).inverse()),
+        }
+    }
+
+    fn to_space<G: Adjoint>(_: &Torsor<G>, algebra: &LieAlgebra<G>) -> LieAlgebra<G>
+    where
+        DefaultAllocator: Allocator<G::Dim>,
+    {
+        algebra.clone()
+    }
+
+    fn from_space<G: Adjoint>(_: &Torsor<G>, algebra: &LieAlgebra<G>) -> LieAlgebra<G>
+    where
+        DefaultAllocator: Allocator<G::Dim>,
+    {
+        algebra.clone()
+    }
+}
+
+pub struct Kinematics<G: LieGroup, C = Space>
 where
     DefaultAllocator: Allocator<G::Dim>,
 {
     pub point: Torsor<G>,
+    // For motions, the angular part comes first and then the linear part
     pub velocity: LieAlgebra<G>,
+    // Rate of change of the velocity. The body and space accelerations are related by the same
+    // adjoint as the velocities, as the velocity commutes with itself.
+    pub acceleration: LieAlgebra<G>,
+    _convention: PhantomData<C>,
+}
+
+// The convention is only a marker, which need not be cloneable
+impl<G: LieGroup + Clone, C> Clone for Kinematics<G, C>
+where
+    DefaultAllocator: Allocator<G::Dim>,
+{
+    fn clone(&self) -> Self {
+        Self::with_convention(self.point.clone(), self.velocity.clone())
+            .with_acceleration(self.acceleration.clone())
+    }
 }
 
-impl<G: LieGroup> HasVelocity for Kinematics<G>
+impl<G: LieGroup, C> HasVelocity for Kinematics<G, C>
 where
     DefaultAllocator: Allocator<G::Dim>,
 {
     type Velocity = LieAlgebra<G>;
 }
 
-impl<G: LieGroup> Kinematics<G>
+impl<G: LieGroup, C> HasAcceleration for Kinematics<G, C>
 where
     DefaultAllocator: Allocator<G::Dim>,
 {
-    pub fn new(point: Torsor<G>, velocity: LieAlgebra<G>) -> Self {
-        Self { point, velocity }
+    type Acceleration = LieAlgebra<G>;
+}
+
+impl<G: LieGroup, C> Kinematics<G, C>
+where
+    DefaultAllocator: Allocator<G::Dim>,
+{
+    // State with the velocity in the convention C, e.g. `Kinematics::<_, Body>::with_convention`
+    pub fn with_convention(point: Torsor<G>, velocity: LieAlgebra<G>) -> Self {
+        Self {
+            point,
+            acceleration: velocity.scale(G::Field::zero()),
+            velocity,
+            _convention: PhantomData,
+        }
     }
 
-    pub fn stationary(point: Torsor<G>) -> Self {
+    pub fn with_acceleration(self, acceleration: LieAlgebra<G>) -> Self {
         Self {
-            point: point,
-            velocity: <Self as HasVelocity>::Velocity::zero(),
+            acceleration,
+            ..self
         }
     }
+}
+
+impl<G: LieGroup> Kinematics<G>
+where
+    DefaultAllocator: Allocator<G::Dim>,
+{
+    pub fn new(point: Torsor<G>, velocity: LieAlgebra<G>) -> Self {
+        Self::with_convention(point, velocity)
+    }
+
+    pub fn stationary(point: Torsor<G>) -> Self
+    where
+        G::Dim: DimName,
+    {
+        Self::new(point, <Self as HasVelocity>::Velocity::zero())
+    }
 
     // pub fn velocity_from_local<const N: usize, Q: Torsor<N, H>, H: LieGroup<N>>(
     //     &self,
@@ -58,3 +186,71 @@ where
     //     }
     // }
 }
+
+impl<G: ExponentialMap, C: Convention> Kinematics<G, C>
+where
+    DefaultAllocator: Allocator<G::Dim>,
+{
+    // State after moving with the constant acceleration for `dt`
+    pub fn advance(&self, dt: G::Field) -> Self {
+        self.advance_with(&self.acceleration, dt)
+    }
+
+    // State after moving with another constant acceleration, in the same convention as the
+    // velocity, for `dt`. The pose follows the mean velocity over the step, which is exact when
+    // the velocity and the acceleration commute, as for a spin about a fixed axis, and accurate
+    // to second order in `dt` otherwise.
+    pub fn advance_with(
+        &self,
+        acceleration: &<Self as HasAcceleration>::Acceleration,
+        dt: G::Field,
+    ) -> Self {
+        let half = dt * nalgebra::convert::<f64, G::Field>(0.5);
+        let mean = self.velocity.add(&acceleration.scale(half));
+        Self::with_convention(
+            C::advance(&self.point, &mean.scale(dt)),
+            self.velocity.add(&acceleration.scale(dt)),
+        )
+        .with_acceleration(acceleration.clone())
+    }
+}
+
+impl<G: Adjoint, C: Convention> Kinematics<G, C>
+where
+    DefaultAllocator: Allocator<G::Dim>,
+{
+    pub fn space_velocity(&self) -> LieAlgebra<G> {
+        C::to_space(&self.point, &self.velocity)
+    }
+
+    pub fn body_velocity(&self) -> LieAlgebra<G> {
+        Body::from_space(&self.point, &self.space_velocity())
+    }
+
+    pub fn space_acceleration(&self) -> LieAlgebra<G> {
+        C::to_space(&self.point, &self.acceleration)
+    }
+
+    pub fn body_acceleration(&self) -> LieAlgebra<G> {
+        Body::from_space(&self.point, &self.space_acceleration())
+    }
+
+    // The same state with the velocity and acceleration in the convention D
+    pub fn to_convention<D: Convention>(&self) -> Kinematics<G, D>
+    where
+        G: Clone,
+    {
+        Kinematics::with_convention(
+            self.point.clone(),
+            D::from_space(&self.point, &self.space_velocity()),
+        )
+        .with_acceleration(D::from_space(&self.point, &self.space_acceleration()))
+    }
+
+    // Rate of change of the components in the body of an element of the algebra which is fixed in
+    // the reference, that is the derivative of the adjoint by `_from_origin` as the body moves,
+    // d/dt Ad(g⁻¹) η = -[ξ_b, Ad(g⁻¹) η]
+    pub fn adjoint_rate(&self, algebra: &LieAlgebra<G>) -> LieAlgebra<G> {
+        G::bracket(&algebra.in_frame(&self.point), &self.body_velocity())
+    }
+}
@@ -0,0 +1,258 @@
+use nalgebra::U3;
+
+use crate::autodiff::{Dual, HyperDual};
+use crate::euclidean::motion::{join, split};
+use crate::euclidean::{EuclideanSpace, LinearSpace, Motion3D, Torsor, Vector};
+use crate::kinematics::{Convention, Kinematics};
+use crate::lie::LieAlgebra;
+use crate::linear::SpecialEuclideanGroup;
+use crate::linear::basis::OrthonormalLinearBasis;
+use crate::linear::exterior::Orientation;
+use crate::linear::group::euclidean::SERepr;
+use crate::manifold::Scalar;
+
+// State of a frame moving relative to the reference at some instant: the position of its origin
+// and its axes, the velocity of the origin and the angular velocity of the axes, and their rates
+// of change in the reference. Angular velocities are oriented by the reference orientation.
+//
+// A rate of change depends on the frame it is seen from, as the axes of a rotating frame carry
+// vectors with them. The methods below relate rates seen from this frame and from the reference
+// by the transport theorem, d/dt|ref q = d/dt|frame q + ω × q, and its consequences for the
+// velocities and accelerations of points. All values are coordinate-free.
+
+#[derive(Clone, Debug)]
+pub struct MovingFrame<K: Scalar = f64> {
+    pub origin: EuclideanSpace<U3, K>,
+    pub axes: OrthonormalLinearBasis<Vector<U3, K>>,
+    pub velocity: Vector<U3, K>,
+    pub angular_velocity: Vector<U3, K>,
+    pub acceleration: Vector<U3, K>,
+    pub angular_acceleration: Vector<U3, K>,
+}
+
+impl<K: Scalar> MovingFrame<K> {
+    pub fn stationary(
+        origin: EuclideanSpace<U3, K>,
+        axes: OrthonormalLinearBasis<Vector<U3, K>>,
+    ) -> Self {
+        Self {
+            origin,
+            axes,
+            velocity: Vector::zero(),
+            angular_velocity: Vector::zero(),
+            acceleration: Vector::zero(),
+            angular_acceleration: Vector::zero(),
+        }
+    }
+
+    // Frame rotating uniformly about an axis through its origin
+    pub fn rotating(
+        origin: EuclideanSpace<U3, K>,
+        axes: OrthonormalLinearBasis<Vector<U3, K>>,
+        angular_velocity: Vector<U3, K>,
+    ) -> Self {
+        Self {
+            angular_velocity,
+            ..Self::stationary(origin, axes)
+        }
+    }
+
+    // Pose of the frame, taking reference components to components in the frame
+    pub fn pose(&self) -> Torsor<Motion3D<K>> {
+        let rotation = self
+            .axes
+            ._from_origin
+            .repr
+            .to_rotation_matrix()
+            .into_inner();
+        let translation = Vector::_from_raw(-(rotation * self.origin._from_origin._get_raw()));
+        Torsor {
+            _from_origin: SpecialEuclideanGroup {
+                repr: U3::from_parts(&rotation, &translation),
+            },
+        }
+    }
+
+    // Pose together with the space velocity and acceleration. The angular part of the velocity is
+    // the angular velocity and its linear part is the velocity of the point of the frame at the
+    // reference origin. Their rates are the space acceleration, which for the linear part is not
+    // the acceleration of any point of the frame, as different points pass the reference origin
+    // over time.
+    pub fn kinematics(&self) -> Kinematics<Motion3D<K>> {
+        let origin = &self.origin._from_origin;
+        let at_origin = &self.velocity - &cross(&self.angular_velocity, origin);
+        // d/dt (V - ω × o) = A - α × o - ω × V
+        let at_origin_rate = &(&self.acceleration - &cross(&self.angular_acceleration, origin))
+            - &cross(&self.angular_velocity, &self.velocity);
+        let twist = |angular: &Vector<U3, K>, linear: &Vector<U3, K>| {
+            LieAlgebra::_from_raw(join(angular._get_raw(), linear._get_raw()))
+        };
+        Kinematics::new(self.pose(), twist(&self.angular_velocity, &at_origin))
+            .with_acceleration(twist(&self.angular_acceleration, &at_origin_rate))
+    }
+
+    pub fn from_kinematics<C: Convention>(kinematics: &Kinematics<Motion3D<K>, C>) -> Self {
+        let origin = kinematics.point.origin();
+        Self {
+            axes: kinematics.point.basis(),
+            velocity: kinematics.point_velocity(&origin),
+            angular_velocity: kinematics.angular_velocity(),
+            acceleration: kinematics.point_acceleration(&origin),
+            angular_acceleration: kinematics.angular_acceleration(),
+            origin,
+        }
+    }
+
+    // Rate of a vector quantity seen from the frame, given its rate seen from the reference
+    pub fn rate_in_frame(&self, vector: &Vector<U3, K>, rate: &Vector<U3, K>) -> Vector<U3, K> {
+        rate - &cross(&self.angular_velocity, vector)
+    }
+
+    pub fn rate_in_reference(
+        &self,
+        vector: &Vector<U3, K>,
+        rate_in_frame: &Vector<U3, K>,
+    ) -> Vector<U3, K> {
+        rate_in_frame + &cross(&self.angular_velocity, vector)
+    }
+
+    // Velocity of a point seen from the frame, v - V - ω × r with r the position from the origin
+    pub fn velocity_in_frame(
+        &self,
+        point: &EuclideanSpace<U3, K>,
+        velocity: &Vector<U3, K>,
+    ) -> Vector<U3, K> {
+        let r = point - &self.origin;
+        self.rate_in_frame(&r, &(velocity - &self.velocity))
+    }
+
+    pub fn velocity_in_reference(
+        &self,
+        point: &EuclideanSpace<U3, K>,
+        velocity_in_frame: &Vector<U3, K>,
+    ) -> Vector<U3, K> {
+        let r = point - &self.origin;
+        &self.rate_in_reference(&r, velocity_in_frame) + &self.velocity
+    }
+
+    // Acceleration of a point seen from the frame, removing the acceleration of the origin and
+    // the Euler, centrifugal and Coriolis terms
+    pub fn acceleration_in_frame(
+        &self,
+        point: &EuclideanSpace<U3, K>,
+        velocity: &Vector<U3, K>,
+        acceleration: &Vector<U3, K>,
+    ) -> Vector<U3, K> {
+        let r = point - &self.origin;
+        let velocity_in_frame = self.velocity_in_frame(point, velocity);
+        acceleration - &self.frame_acceleration(&r, &velocity_in_frame)
+    }
+
+    pub fn acceleration_in_reference(
+        &self,
+        point: &EuclideanSpace<U3, K>,
+        velocity_in_frame: &Vector<U3, K>,
+        acceleration_in_frame: &Vector<U3, K>,
+    ) -> Vector<U3, K> {
+        let r = point - &self.origin;
+        acceleration_in_frame + &self.frame_acceleration(&r, velocity_in_frame)
+    }
+
+    // A + α × r + ω × (ω × r) + 2 ω × v
+    fn frame_acceleration(
+        &self,
+        r: &Vector<U3, K>,
+        velocity_in_frame: &Vector<U3, K>,
+    ) -> Vector<U3, K> {
+        let w = &self.angular_velocity;
+        let euler = cross(&self.angular_acceleration, r);
+        let centrifugal = cross(w, &cross(w, r));
+        let coriolis = cross(w, velocity_in_frame) * (K::one() + K::one());
+        &(&(&self.acceleration + &euler) + &centrifugal) + &coriolis
+    }
+}
+
+// Classical velocities and accelerations of a rigid body, the rates of change of the positions of
+// the points of the body, as opposed to the space velocity and acceleration which are taken at a
+// fixed point of the reference
+
+impl<K: Scalar, C: Convention> Kinematics<Motion3D<K>, C> {
+    pub fn angular_velocity(&self) -> Vector<U3, K> {
+        Vector::_from_raw(split(self.space_velocity()._get_raw()).0)
+    }
+
+    pub fn angular_acceleration(&self) -> Vector<U3, K> {
+        Vector::_from_raw(split(self.space_acceleration()._get_raw()).0)
+    }
+
+    // Velocity of the point of the body which is at `point`, v + ω × p
+    pub fn point_velocity(&self, point: &EuclideanSpace<U3, K>) -> Vector<U3, K> {
+        let (w, v) = split(self.space_velocity()._get_raw());
+        Vector::_from_raw(v + w.cross(point._from_origin._get_raw()))
+    }
+
+    // Acceleration of the point of the body which is at `point`, dv/dt + dω/dt × p + ω × (v + ω × p)
+    pub fn point_acceleration(&self, point: &EuclideanSpace<U3, K>) -> Vector<U3, K> {
+        let (w, _) = split(self.space_velocity()._get_raw());
+        let (w_rate, v_rate) = split(self.space_acceleration()._get_raw());
+        let p = point._from_origin._get_raw();
+        let velocity = self.point_velocity(point);
+        Vector::_from_raw(v_rate + w_rate.cross(p) + w.cross(velocity._get_raw()))
+    }
+}
+
+// Frames given as functions of time, such as closures `|t| MovingFrame { .. }`
+
+pub trait FrameTrajectory<K: Scalar> {
+    fn at(&self, t: K) -> MovingFrame<K>;
+
+    // Rate at time t of a vector quantity seen from the frame, for the quantity given as a
+    // function of time in the reference
+    fn rate_of(&self, quantity: impl Fn(Dual<K>) -> Vector<U3, Dual<K>>, t: K) -> Vector<U3, K> {
+        let q = quantity(Dual::variable(t));
+        let value = Vector::_from_raw(q._get_raw().map(|x| x.value()));
+        let rate = Vector::_from_raw(q._get_raw().map(|x| x.derivative()));
+        self.at(t).rate_in_frame(&value, &rate)
+    }
+
+    // Velocity at time t of a moving point seen from the frame
+    fn velocity_of(
+        &self,
+        position: impl Fn(Dual<K>) -> EuclideanSpace<U3, Dual<K>>,
+        t: K,
+    ) -> Vector<U3, K> {
+        let p = position(Dual::variable(t))._from_origin;
+        let point = Torsor {
+            _from_origin: Vector::_from_raw(p._get_raw().map(|x| x.value())),
+        };
+        let velocity = Vector::_from_raw(p._get_raw().map(|x| x.derivative()));
+        self.at(t).velocity_in_frame(&point, &velocity)
+    }
+
+    // Acceleration at time t of a moving point seen from the frame
+    fn acceleration_of(
+        &self,
+        position: impl Fn(HyperDual<K>) -> EuclideanSpace<U3, HyperDual<K>>,
+        t: K,
+    ) -> Vector<U3, K> {
+        let p = position(Dual::new(Dual::variable(t), Dual::new(K::one(), K::zero())))._from_origin;
+        let raw = p._get_raw();
+        let point = Torsor {
+            _from_origin: Vector::_from_raw(raw.map(|x| x.value().value())),
+        };
+        let velocity = Vector::_from_raw(raw.map(|x| x.value().derivative()));
+        let acceleration = Vector::_from_raw(raw.map(|x| x.derivative().derivative()));
+        self.at(t)
+            .acceleration_in_frame(&point, &velocity, &acceleration)
+    }
+}
+
+impl<K: Scalar, F: Fn(K) -> MovingFrame<K>> FrameTrajectory<K> for F {
+    fn at(&self, t: K) -> MovingFrame<K> {
+        self(t)
+    }
+}
+
+fn cross<K: Scalar>(a: &Vector<U3, K>, b: &Vector<U3, K>) -> Vector<U3, K> {
+    Orientation::reference().cross(a, b)
+}
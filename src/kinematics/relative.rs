@@ -0,0 +1,56 @@
+use crate::euclidean::Motion3D;
+use crate::kinematics::{Kinematics, MovingFrame};
+use crate::lie::{Adjoint, Allocator, DefaultAllocator, LieAlgebra, Torsor};
+use crate::manifold::Scalar;
+
+// Motion of one body seen from another. With g_a and g_b the poses taking the reference frame to
+// the bodies and ξ_a and ξ_b their space velocities, the pose of b seen from a is g_a⁻¹ g_b, and
+// its velocity seen from the reference is ξ_b - ξ_a. Its rate of change seen from either body is
+//
+//     α_b - α_a - [ξ_a, ξ_b]
+//
+// for the space accelerations α = dξ/dt, where the bracket holds the Coriolis and centripetal
+// terms. Both are coordinate-free, and `LieAlgebra::in_frame` gives their components in either
+// body: in a, the velocity of b as if a were the reference, and in b, the velocity of b seen
+// from a in the body convention.
+
+impl<G: Adjoint> Kinematics<G>
+where
+    DefaultAllocator: Allocator<G::Dim>,
+{
+    // Frame of `other` with this body as the reference
+    pub fn relative_pose(&self, other: &Self) -> Torsor<G> {
+        Torsor {
+            _from_origin: other
+                .point
+                ._from_origin
+                .multiply(&self.point._from_origin.inverse()),
+        }
+    }
+
+    pub fn relative_velocity(&self, other: &Self) -> LieAlgebra<G> {
+        other.velocity.sub(&self.velocity)
+    }
+
+    pub fn relative_acceleration(&self, other: &Self) -> LieAlgebra<G> {
+        let bracket = G::bracket(&self.velocity, &other.velocity);
+        other.acceleration.sub(&self.acceleration).sub(&bracket)
+    }
+
+    // State of `other` with this body as the reference
+    pub fn relative_kinematics(&self, other: &Self) -> Self {
+        Self::new(
+            self.relative_pose(other),
+            self.relative_velocity(other).in_frame(&self.point),
+        )
+        .with_acceleration(self.relative_acceleration(other).in_frame(&self.point))
+    }
+}
+
+impl<K: Scalar> Kinematics<Motion3D<K>> {
+    // Frame of `other` moving relative to this body as the reference, with the classical
+    // velocities and accelerations of its origin and axes
+    pub fn relative_frame(&self, other: &Self) -> MovingFrame<K> {
+        MovingFrame::from_kinematics(&self.relative_kinematics(other))
+    }
+}
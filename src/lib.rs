@@ -1,14 +1,25 @@
 // #![feature(generic_const_exprs)]
 // #![allow(incomplete_features)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 pub mod astrodynamics;
+pub mod autodiff;
+pub mod dynamics;
+pub mod error;
 pub mod euclidean;
+pub mod frames;
 pub mod kinematics;
 // pub mod linear_space;
 pub mod manifold;
+#[cfg(feature = "serde")]
+pub mod serialize;
 
 pub mod lie;
 pub mod linear;
 
 // pub use linear_space::{AffineFrame, AffineSpace, Basis, LinearSpace};
+pub use error::{Error, Result};
 pub use manifold::Scalar;
@@ -1,13 +1,27 @@
+use crate::error::Result;
 use crate::manifold::Manifold;
-use nalgebra::OVector;
 pub use nalgebra::{DefaultAllocator, allocator::Allocator};
+use nalgebra::{DimName, OVector};
 
 pub trait LieGroup: Manifold {
-    fn identity() -> Self;
+    // Only groups of a dimension known at compile time have an identity without being given
+    // their size
+    fn identity() -> Self
+    where
+        Self::Dim: DimName;
     fn multiply(&self, other: &Self) -> Self;
     fn inverse(&self) -> Self;
+
+    // Product which fails instead of panicking when the sizes of runtime-sized elements differ
+    fn try_multiply(&self, other: &Self) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        Ok(self.multiply(other))
+    }
 }
 
+#[derive(Debug)]
 pub struct LieAlgebra<G: LieGroup>
 where
     DefaultAllocator: Allocator<G::Dim>,
@@ -15,17 +29,91 @@ where
     raw: OVector<G::Field, G::Dim>,
 }
 
+// Elements of the algebra can be copied without the group elements being cloneable
+impl<G: LieGroup> Clone for LieAlgebra<G>
+where
+    DefaultAllocator: Allocator<G::Dim>,
+{
+    fn clone(&self) -> Self {
+        LieAlgebra {
+            raw: self.raw.clone(),
+        }
+    }
+}
+
 impl<G: LieGroup> LieAlgebra<G>
 where
     DefaultAllocator: Allocator<G::Dim>,
 {
-    pub fn zero() -> Self {
+    pub fn zero() -> Self
+    where
+        G::Dim: DimName,
+    {
         LieAlgebra {
             raw: OVector::zeros(),
         }
     }
+
+    pub fn add(&self, other: &Self) -> Self {
+        LieAlgebra {
+            raw: &self.raw + &other.raw,
+        }
+    }
+
+    pub fn sub(&self, other: &Self) -> Self {
+        LieAlgebra {
+            raw: &self.raw - &other.raw,
+        }
+    }
+
+    pub fn scale(&self, scalar: G::Field) -> Self {
+        LieAlgebra {
+            raw: &self.raw * scalar,
+        }
+    }
+
+    pub fn _get_raw(&self) -> &OVector<G::Field, G::Dim> {
+        &self.raw
+    }
+    pub fn _from_raw(raw: OVector<G::Field, G::Dim>) -> Self {
+        LieAlgebra { raw }
+    }
+}
+
+// Groups whose exponential map is known in closed form. `exp` gives the element reached from the
+// identity by following the one-parameter subgroup of the given element for unit time.
+pub trait ExponentialMap: LieGroup
+where
+    DefaultAllocator: Allocator<Self::Dim>,
+{
+    fn exp(algebra: &LieAlgebra<Self>) -> Self;
 }
 
+// Groups whose adjoint representation is known. `adjoint` gives g ξ g⁻¹ and `bracket` the
+// commutator [a, b] = a b - b a. For a velocity seen from the reference, the adjoint by the
+// `_from_origin` of a frame gives the components of the same velocity in that frame.
+pub trait Adjoint: LieGroup
+where
+    DefaultAllocator: Allocator<Self::Dim>,
+{
+    fn adjoint(&self, algebra: &LieAlgebra<Self>) -> LieAlgebra<Self>;
+    fn bracket(a: &LieAlgebra<Self>, b: &LieAlgebra<Self>) -> LieAlgebra<Self>;
+}
+
+impl<G: Adjoint> LieAlgebra<G>
+where
+    DefaultAllocator: Allocator<G::Dim>,
+{
+    pub fn in_frame(&self, frame: &Torsor<G>) -> Self {
+        frame._from_origin.adjoint(self)
+    }
+
+    pub fn from_frame(&self, frame: &Torsor<G>) -> Self {
+        frame._from_origin.inverse().adjoint(self)
+    }
+}
+
+#[derive(Debug)]
 pub struct LieAlgebraDual<G: LieGroup>
 where
     DefaultAllocator: Allocator<G::Dim>,
@@ -33,6 +121,56 @@ where
     raw: OVector<G::Field, G::Dim>,
 }
 
+impl<G: LieGroup> Clone for LieAlgebraDual<G>
+where
+    DefaultAllocator: Allocator<G::Dim>,
+{
+    fn clone(&self) -> Self {
+        LieAlgebraDual {
+            raw: self.raw.clone(),
+        }
+    }
+}
+
+impl<G: LieGroup> LieAlgebraDual<G>
+where
+    DefaultAllocator: Allocator<G::Dim>,
+{
+    pub fn zero() -> Self
+    where
+        G::Dim: DimName,
+    {
+        LieAlgebraDual {
+            raw: OVector::zeros(),
+        }
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        LieAlgebraDual {
+            raw: &self.raw + &other.raw,
+        }
+    }
+
+    pub fn scale(&self, scalar: G::Field) -> Self {
+        LieAlgebraDual {
+            raw: &self.raw * scalar,
+        }
+    }
+
+    // Value on an element of the algebra, e.g. the power of a wrench on a velocity
+    pub fn pair(&self, algebra: &LieAlgebra<G>) -> G::Field {
+        self.raw.dot(&algebra.raw)
+    }
+
+    pub fn _get_raw(&self) -> &OVector<G::Field, G::Dim> {
+        &self.raw
+    }
+    pub fn _from_raw(raw: OVector<G::Field, G::Dim>) -> Self {
+        LieAlgebraDual { raw }
+    }
+}
+
+#[derive(Clone, Debug)]
 pub struct Torsor<G: LieGroup> {
     pub _from_origin: G,
 }
@@ -44,6 +182,11 @@ impl<G: LieGroup> Manifold for Torsor<G> {
 
 pub trait GroupAction<M: Manifold>: LieGroup {
     fn act_on(&self, point: &M) -> M;
+
+    // Action which fails instead of panicking when runtime sizes differ
+    fn try_act_on(&self, point: &M) -> Result<M> {
+        Ok(self.act_on(point))
+    }
 }
 
 impl<G: LieGroup> GroupAction<Torsor<G>> for G {
@@ -52,6 +195,12 @@ impl<G: LieGroup> GroupAction<Torsor<G>> for G {
             _from_origin: self.multiply(&point._from_origin),
         }
     }
+
+    fn try_act_on(&self, point: &Torsor<G>) -> Result<Torsor<G>> {
+        Ok(Torsor {
+            _from_origin: self.try_multiply(&point._from_origin)?,
+        })
+    }
 }
 
 // pub trait ChartTransformation<const N: usize, const D: usize, C: Chart<N>> {
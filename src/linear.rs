@@ -1,7 +1,22 @@
 pub mod basis;
+pub mod exterior;
+pub mod frame;
 pub mod group;
+pub mod map;
+pub mod metric;
 pub mod space;
-// pub mod tensor;
+#[cfg(feature = "alloc")]
+pub mod subspace;
+#[cfg(feature = "alloc")]
+pub mod tensor;
 
+pub use exterior::{Bivector, Orientation};
+pub use frame::{AffineFrame, OrthonormalAffineFrame};
 pub use group::{GeneralLinearGroup, SpecialEuclideanGroup, SpecialOrthogonalGroup};
-pub use space::{AffineSpace, InnerProductSpace, LinearSpace};
+pub use map::LinearMap;
+pub use metric::{Metric, MetricCovector, MetricVector, PositiveDefinite};
+pub use space::{AffineSpace, InnerProductSpace, LinearSpace, MetricSpace};
+#[cfg(feature = "alloc")]
+pub use subspace::{AffineSubspace, LinearSubspace};
+#[cfg(feature = "alloc")]
+pub use tensor::Tensor;
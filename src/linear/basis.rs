@@ -1,12 +1,19 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::error::{Error, Result};
 use crate::lie::{GroupAction, LieGroup, Torsor};
 use crate::linear::InnerProductSpace;
+use crate::linear::exterior::Orientation;
 use crate::linear::group::orthogonal::SORepr;
 use crate::linear::group::{DimOfGL, DimOfSO, GeneralLinearGroup, SpecialOrthogonalGroup};
 use crate::linear::space::LinearSpace;
-use crate::manifold::{Chart, Manifold};
+use crate::manifold::{Chart, Components, One, Scalar, Zero};
+use approx::AbsDiffEq;
+use generic_array::typenum::Unsigned;
 use generic_array::{ArrayLength, GenericArray};
+use nalgebra::{ComplexField, Dim, DimMin, DimName, OMatrix, OVector, ToTypenum, U1};
 use nalgebra::{DefaultAllocator, allocator::Allocator};
-use nalgebra::{Dim, OVector};
 
 pub type LinearBasis<V = f64> = Torsor<GeneralLinearGroup<V>>;
 
@@ -15,35 +22,111 @@ where
     DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
     V::Dim: DimOfGL,
 {
-    fn from_local(&self, components: &GenericArray<V::Field, V::Dim>) -> V
+    fn from_local(&self, components: &Components<V>) -> V
     where
-        V::Dim: ArrayLength,
+        V::Dim: ToTypenum<Typenum: ArrayLength>,
     {
-        self._from_origin
-            .inverse()
-            .act_on(&V::_from_raw(OVector::from_column_slice(
-                components.as_slice(),
-            )))
+        self.from_local_slice(components.as_slice())
     }
 
-    fn to_local(&self, point: &V) -> GenericArray<V::Field, V::Dim>
+    fn to_local(&self, point: &V) -> Components<V>
     where
-        V::Dim: ArrayLength,
+        V::Dim: ToTypenum<Typenum: ArrayLength>,
     {
         GenericArray::from_slice(self._from_origin.act_on(point)._get_raw().as_slice()).clone()
     }
 }
 
+impl<V: LinearSpace> LinearBasis<V>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+    V::Dim: DimOfGL,
+{
+    // Basis with the given vectors, rejecting sets that are singular or ill-conditioned
+    pub fn try_from_vectors(vectors: &[V]) -> Result<Self> {
+        let dim = vectors.first().map_or(0, V::dim);
+        if vectors.iter().any(|v| v.dim() != dim) {
+            return Err(Error::DimensionMismatch);
+        }
+        if vectors.len() != V::Dim::try_to_usize().unwrap_or(dim) || vectors.len() != dim {
+            return Err(Error::DimensionMismatch);
+        }
+        let dim = V::Dim::from_usize(dim);
+        let from_local = OMatrix::from_fn_generic(dim, dim, |i, j| vectors[j]._get_raw()[i]);
+        Ok(Self {
+            _from_origin: GeneralLinearGroup::try_from_matrix(from_local)?.inverse(),
+        })
+    }
+
+    // Basis vectors, the columns of the inverse of the component map
+    #[cfg(feature = "alloc")]
+    pub fn vectors(&self) -> Vec<V> {
+        let inverse = &self._from_origin.inverse;
+        inverse
+            .column_iter()
+            .map(|column| V::_from_raw(column.into_owned()))
+            .collect()
+    }
+
+    pub fn dim(&self) -> usize {
+        self._from_origin.dim()
+    }
+
+    // Components of a vector, also for runtime-sized spaces
+    #[cfg(feature = "alloc")]
+    pub fn to_local_vec(&self, vector: &V) -> Vec<V::Field> {
+        self.try_to_local_vec(vector)
+            .expect("Dimensions do not match")
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn try_to_local_vec(&self, vector: &V) -> Result<Vec<V::Field>> {
+        if vector.dim() != self.dim() {
+            return Err(Error::DimensionMismatch);
+        }
+        Ok((&self._from_origin.matrix * vector._get_raw())
+            .as_slice()
+            .to_vec())
+    }
+
+    pub fn from_local_slice(&self, components: &[V::Field]) -> V {
+        self.try_from_local_slice(components)
+            .expect("Dimensions do not match")
+    }
+
+    pub fn try_from_local_slice(&self, components: &[V::Field]) -> Result<V> {
+        if components.len() != self.dim() {
+            return Err(Error::DimensionMismatch);
+        }
+        let components = OVector::from_column_slice_generic(
+            V::Dim::from_usize(components.len()),
+            U1,
+            components,
+        );
+        Ok(V::_from_raw(&self._from_origin.inverse * components))
+    }
+
+    // Dual basis, whose covectors take a vector to its components, the rows of the component map
+    #[cfg(feature = "alloc")]
+    pub fn dual_vectors(&self) -> Vec<V::DualSpace> {
+        let matrix = &self._from_origin.matrix;
+        matrix
+            .row_iter()
+            .map(|row| V::DualSpace::_from_raw(row.transpose()))
+            .collect()
+    }
+}
+
 pub type OrthonormalLinearBasis<V = f64> = Torsor<SpecialOrthogonalGroup<V>>;
 
 impl<V: InnerProductSpace> Chart<V> for OrthonormalLinearBasis<V>
 where
     DefaultAllocator: Allocator<V::Dim>,
-    V::Dim: DimOfSO + SORepr<V>,
+    V::Dim: DimName + DimOfSO + SORepr<V>,
 {
-    fn from_local(&self, components: &GenericArray<V::Field, V::Dim>) -> V
+    fn from_local(&self, components: &Components<V>) -> V
     where
-        V::Dim: ArrayLength,
+        V::Dim: ToTypenum<Typenum: ArrayLength>,
     {
         self._from_origin
             .inverse()
@@ -52,10 +135,182 @@ where
             )))
     }
 
-    fn to_local(&self, point: &V) -> GenericArray<V::Field, V::Dim>
+    fn to_local(&self, point: &V) -> Components<V>
     where
-        V::Dim: ArrayLength,
+        V::Dim: ToTypenum<Typenum: ArrayLength>,
     {
         GenericArray::from_slice(self._from_origin.act_on(point)._get_raw().as_slice()).clone()
     }
 }
+
+impl<V: InnerProductSpace> OrthonormalLinearBasis<V>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+    V::Dim: DimName + DimOfSO + SORepr<V>,
+{
+    // Gram-Schmidt: each `(axis, vector)` pair fixes the direction of that axis of the basis,
+    // after removing its components along the axes fixed before it. Axes left unspecified are
+    // completed from the reference basis, and the last of them is oriented so that the basis
+    // has the same handedness as the reference.
+    pub fn from_axes(axes: &[(usize, &V)]) -> Result<Self>
+    where
+        V::Dim: DimMin<V::Dim, Output = V::Dim>,
+    {
+        let matrix = Self::orthonormal_columns(axes, true)?;
+        // The chart takes a vector to its components along the columns
+        Ok(Self {
+            _from_origin: SpecialOrthogonalGroup {
+                repr: <V::Dim as SORepr<V>>::from_matrix(&matrix.transpose()),
+            },
+        })
+    }
+
+    // Columns of the basis built by `from_axes`, with a positive or negative determinant
+    fn orthonormal_columns(
+        axes: &[(usize, &V)],
+        positive: bool,
+    ) -> Result<OMatrix<V::Field, V::Dim, V::Dim>>
+    where
+        V::Dim: DimMin<V::Dim, Output = V::Dim>,
+    {
+        let n = V::Dim::dim();
+        // Columns fixed so far, marked in `set`, kept on the stack
+        let mut matrix = OMatrix::<V::Field, V::Dim, V::Dim>::zeros();
+        let mut set = OVector::<bool, V::Dim>::from_element(false);
+
+        let orthogonalize = |matrix: &OMatrix<V::Field, V::Dim, V::Dim>,
+                             set: &OVector<bool, V::Dim>,
+                             vector: &V| {
+            let raw = (0..n)
+                .filter(|j| set[*j])
+                .map(|j| V::_from_raw(matrix.column(j).into_owned()))
+                .fold(vector._get_raw().clone(), |raw, e| {
+                    raw - e._get_raw() * e.dot(vector)
+                });
+            V::_from_raw(raw)
+        };
+        let tolerance = V::Field::default_epsilon().sqrt();
+
+        for (axis, vector) in axes {
+            if *axis >= n || set[*axis] {
+                return Err(Error::InvalidAxis);
+            }
+            let norm = vector.dot(vector).sqrt();
+            if norm == V::Field::zero() {
+                return Err(Error::ZeroVector);
+            }
+            let e = orthogonalize(&matrix, &set, vector);
+            let residual = e.dot(&e).sqrt();
+            if residual <= tolerance * norm {
+                return Err(Error::Degenerate);
+            }
+            matrix.set_column(*axis, e.scale(V::Field::one() / residual)._get_raw());
+            set[*axis] = true;
+        }
+
+        let free = set.map(|set| !set);
+        let last_free = (0..n).rev().find(|i| free[*i]);
+        let mut candidates = (0..n).map(|i| V::_from_raw(unit(V::Dim::name(), i)));
+        for axis in (0..n).filter(|i| free[*i]) {
+            let e = loop {
+                let candidate = candidates.next().expect("Reference basis is degenerate");
+                let e = orthogonalize(&matrix, &set, &candidate);
+                let residual = e.dot(&e).sqrt();
+                if residual > tolerance {
+                    break e.scale(V::Field::one() / residual);
+                }
+            };
+            matrix.set_column(axis, e._get_raw());
+            set[axis] = true;
+        }
+
+        if (matrix.determinant() > V::Field::zero()) != positive {
+            let Some(last) = last_free else {
+                return Err(Error::LeftHanded);
+            };
+            matrix.set_column(last, &-matrix.column(last));
+        }
+        Ok(matrix)
+    }
+}
+
+impl<V: InnerProductSpace> LinearBasis<V>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+    V::Dim: DimName + DimOfSO + SORepr<V> + DimOfGL + DimMin<V::Dim, Output = V::Dim>,
+{
+    // Orthonormal basis built as by `OrthonormalLinearBasis::from_axes`, but positive in the given
+    // orientation, so that it is left-handed for the reversed reference orientation
+    pub fn orthonormal_from_axes(
+        axes: &[(usize, &V)],
+        orientation: &Orientation<V>,
+    ) -> Result<Self> {
+        let matrix = OrthonormalLinearBasis::orthonormal_columns(axes, orientation.is_reference())?;
+        Ok(Self {
+            _from_origin: GeneralLinearGroup::try_from_matrix(matrix)?.inverse(),
+        })
+    }
+}
+
+// Charts whose component map is a linear isomorphism, so that the components of tensors and
+// dual vectors can be derived from those of vectors
+
+pub trait LinearChart<V: LinearSpace>: Chart<V>
+where
+    DefaultAllocator: Allocator<V::Dim>,
+{
+    // Matrix taking reference components (those of `_get_raw`) to components in this chart
+    fn _to_local_matrix(&self) -> OMatrix<V::Field, V::Dim, V::Dim>
+    where
+        DefaultAllocator: Allocator<V::Dim, V::Dim>,
+        V::Dim: ToTypenum<Typenum: ArrayLength>,
+    {
+        let dim = V::Dim::from_usize(<V::Dim as ToTypenum>::Typenum::USIZE);
+        OMatrix::from_fn_generic(dim, dim, |i, j| {
+            self.to_local(&V::_from_raw(unit(dim, j)))[i]
+        })
+    }
+
+    // Matrix taking components in this chart to reference components
+    fn _from_local_matrix(&self) -> OMatrix<V::Field, V::Dim, V::Dim>
+    where
+        DefaultAllocator: Allocator<V::Dim, V::Dim>,
+        V::Dim: ToTypenum<Typenum: ArrayLength>,
+    {
+        let dim = V::Dim::from_usize(<V::Dim as ToTypenum>::Typenum::USIZE);
+        OMatrix::from_fn_generic(dim, dim, |i, j| {
+            let unit = unit(dim, j);
+            self.from_local(GenericArray::from_slice(unit.as_slice()))
+                ._get_raw()[i]
+        })
+    }
+}
+
+// Components of the i-th reference basis vector
+pub(crate) fn unit<K: Scalar, N: Dim>(dim: N, i: usize) -> OVector<K, N>
+where
+    DefaultAllocator: Allocator<N>,
+{
+    OVector::from_fn_generic(dim, U1, |j, _| if i == j { K::one() } else { K::zero() })
+}
+
+impl<V: LinearSpace> LinearChart<V> for LinearBasis<V>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+    V::Dim: DimOfGL,
+{
+    fn _to_local_matrix(&self) -> OMatrix<V::Field, V::Dim, V::Dim> {
+        self._from_origin.matrix.clone()
+    }
+
+    fn _from_local_matrix(&self) -> OMatrix<V::Field, V::Dim, V::Dim> {
+        self._from_origin.inverse.clone()
+    }
+}
+
+impl<V: InnerProductSpace> LinearChart<V> for OrthonormalLinearBasis<V>
+where
+    DefaultAllocator: Allocator<V::Dim>,
+    V::Dim: DimName + DimOfSO + SORepr<V>,
+{
+}
@@ -0,0 +1,222 @@
+use core::marker::PhantomData;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use generic_array::ArrayLength;
+use nalgebra::{ComplexField, DimMin, DimName, OMatrix, ToTypenum, U3, Vector3};
+
+use crate::error::{Error, Result};
+use crate::linear::LinearMap;
+use crate::linear::basis::LinearChart;
+use crate::linear::space::{
+    Allocator, DefaultAllocator, InnerProductSpace, LinearSpace, MetricSpace,
+};
+use crate::manifold::{One, Zero};
+
+// Bivector over V, an antisymmetric (2, 0) tensor stored as a matrix of reference components
+
+#[derive(Clone, Debug)]
+pub struct Bivector<V: LinearSpace>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+{
+    raw: OMatrix<V::Field, V::Dim, V::Dim>,
+    _space: PhantomData<V>,
+}
+
+impl<V: LinearSpace> Bivector<V>
+where
+    V::Dim: DimName,
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+{
+    pub fn zero() -> Self {
+        Self::_from_raw(OMatrix::<V::Field, V::Dim, V::Dim>::zeros())
+    }
+
+    // a ∧ b = a ⊗ b - b ⊗ a
+    pub fn wedge(a: &V, b: &V) -> Self {
+        let (a, b) = (a._get_raw(), b._get_raw());
+        Self::_from_raw(OMatrix::<V::Field, V::Dim, V::Dim>::from_fn(|i, j| {
+            a[i] * b[j] - b[i] * a[j]
+        }))
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        Self::_from_raw(&self.raw + &other.raw)
+    }
+
+    pub fn scale(&self, scalar: V::Field) -> Self {
+        Self::_from_raw(&self.raw * scalar)
+    }
+
+    // Push-forward by a linear map, so that A(a ∧ b) = Aa ∧ Ab
+    pub fn transform(&self, map: &LinearMap<V, V>) -> Self {
+        let matrix = map._get_raw();
+        Self::_from_raw(matrix * &self.raw * matrix.transpose())
+    }
+
+    // Contraction with a covector in the first slot, B(α, .)
+    pub fn contract(&self, covector: &V::DualSpace) -> V {
+        V::_from_raw(self.raw.tr_mul(covector._get_raw()))
+    }
+
+    // Components in a basis, in row-major order
+    #[cfg(feature = "alloc")]
+    pub fn to_local<C: LinearChart<V>>(&self, basis: &C) -> Vec<V::Field>
+    where
+        V::Dim: ToTypenum<Typenum: ArrayLength>,
+    {
+        let to_local = basis._to_local_matrix();
+        let local = &to_local * &self.raw * to_local.transpose();
+        local.transpose().as_slice().to_vec()
+    }
+
+    pub fn _get_raw(&self) -> &OMatrix<V::Field, V::Dim, V::Dim> {
+        &self.raw
+    }
+    pub fn _from_raw(raw: OMatrix<V::Field, V::Dim, V::Dim>) -> Self {
+        Self {
+            raw,
+            _space: PhantomData,
+        }
+    }
+}
+
+// Orientation of an inner product space, given by the sign of its volume form relative to the
+// reference basis. Pseudo-vectors such as the cross product depend on this choice.
+
+#[derive(Clone, Debug)]
+pub struct Orientation<V: MetricSpace>
+where
+    DefaultAllocator: Allocator<V::Dim>,
+{
+    sign: V::Field,
+    _space: PhantomData<V>,
+}
+
+impl<V: MetricSpace> Orientation<V>
+where
+    V::Dim: DimName,
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+{
+    // Orientation in which the reference basis is positive
+    pub fn reference() -> Self {
+        Self::from_sign(V::Field::one())
+    }
+
+    pub fn reversed(&self) -> Self {
+        Self::from_sign(-self.sign)
+    }
+
+    // Orientation in which the given basis is positive
+    pub fn of_basis<C: LinearChart<V>>(basis: &C) -> Self
+    where
+        V::Dim: DimMin<V::Dim, Output = V::Dim> + ToTypenum<Typenum: ArrayLength>,
+    {
+        Self::reference()
+            .push_forward(&LinearMap::_from_raw(basis._from_local_matrix()))
+            .expect("Basis is singular")
+    }
+
+    // Orientation carried along by a linear isomorphism
+    pub fn push_forward(&self, map: &LinearMap<V, V>) -> Result<Self>
+    where
+        V::Dim: DimMin<V::Dim, Output = V::Dim>,
+    {
+        let determinant = map._get_raw().determinant();
+        if determinant == V::Field::zero() {
+            return Err(Error::Singular);
+        }
+        Ok(Self::from_sign(self.sign * determinant.signum()))
+    }
+
+    pub fn is_reference(&self) -> bool {
+        self.sign > V::Field::zero()
+    }
+
+    // Metric volume form, vol(e_1, ..., e_n) = ±1 on positive orthonormal bases
+    pub fn volume(&self, vectors: &[&V]) -> Result<V::Field>
+    where
+        V::Dim: DimMin<V::Dim, Output = V::Dim>,
+    {
+        if vectors.len() != V::Dim::dim() {
+            return Err(Error::DimensionMismatch);
+        }
+        let matrix = OMatrix::<V::Field, V::Dim, V::Dim>::from_fn(|i, j| vectors[j]._get_raw()[i]);
+        Ok(self.sign * self.scale() * matrix.determinant())
+    }
+
+    // sqrt |det G|, the volume of the reference parallelotope
+    fn scale(&self) -> V::Field
+    where
+        V::Dim: DimMin<V::Dim, Output = V::Dim>,
+    {
+        V::_gram_matrix().determinant().abs().sqrt()
+    }
+
+    fn from_sign(sign: V::Field) -> Self {
+        Self {
+            sign,
+            _space: PhantomData,
+        }
+    }
+}
+
+impl<V: MetricSpace<Dim = U3>> Orientation<V> {
+    // Hodge star of a bivector, (⋆B)_i = 1/2 vol_ijk B^jk raised to a vector, which fails for a
+    // degenerate metric
+    pub fn try_hodge(&self, bivector: &Bivector<V>) -> Result<V> {
+        let b = bivector._get_raw();
+        let raw = Vector3::new(b[(1, 2)], b[(2, 0)], b[(0, 1)]) * (self.sign * self.scale());
+        V::sharp(&V::DualSpace::_from_raw(raw))
+    }
+
+    // Inverse of the Hodge star, B^jk = vol^ijk v_i
+    pub fn try_hodge_inverse(&self, vector: &V) -> Result<Bivector<V>> {
+        let scale = self.scale();
+        if scale == V::Field::zero() {
+            return Err(Error::Singular);
+        }
+        let c = vector.flat()._get_raw() * (self.sign / scale);
+        Ok(Bivector::_from_raw(OMatrix::<V::Field, U3, U3>::new(
+            V::Field::zero(),
+            c[2],
+            -c[1],
+            -c[2],
+            V::Field::zero(),
+            c[0],
+            c[1],
+            -c[0],
+            V::Field::zero(),
+        )))
+    }
+
+    // a × b = ⋆(a ∧ b)
+    pub fn try_cross(&self, a: &V, b: &V) -> Result<V> {
+        self.try_hodge(&Bivector::wedge(a, b))
+    }
+
+    // a · (b × c) = vol(a, b, c)
+    pub fn triple(&self, a: &V, b: &V, c: &V) -> V::Field {
+        self.volume(&[a, b, c])
+            .expect("Three vectors in a 3D space")
+    }
+}
+
+impl<V: InnerProductSpace<Dim = U3>> Orientation<V> {
+    pub fn hodge(&self, bivector: &Bivector<V>) -> V {
+        self.try_hodge(bivector)
+            .expect("Inner products are nondegenerate")
+    }
+
+    pub fn hodge_inverse(&self, vector: &V) -> Bivector<V> {
+        self.try_hodge_inverse(vector)
+            .expect("Inner products are nondegenerate")
+    }
+
+    pub fn cross(&self, a: &V, b: &V) -> V {
+        self.try_cross(a, b)
+            .expect("Inner products are nondegenerate")
+    }
+}
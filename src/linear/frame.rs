@@ -0,0 +1,186 @@
+use generic_array::{ArrayLength, GenericArray};
+use nalgebra::{DimName, OVector, ToTypenum};
+
+use crate::lie::{GroupAction, LieGroup, Torsor};
+use crate::linear::basis::{LinearBasis, OrthonormalLinearBasis};
+use crate::linear::group::euclidean::SERepr;
+use crate::linear::group::orthogonal::SORepr;
+use crate::linear::group::{
+    DimOfGL, DimOfSE, DimOfSO, GeneralLinearGroup, SpecialEuclideanGroup, SpecialOrthogonalGroup,
+};
+use crate::linear::space::LinearSpace;
+use crate::linear::space::{AffineSpace, Allocator, DefaultAllocator, InnerProductSpace};
+use crate::manifold::{Chart, Components, Manifold};
+
+// Affine frames: an origin together with a basis, giving points the components of their
+// displacement from the origin in the basis
+
+#[derive(Clone, Debug)]
+pub struct AffineFrame<V: LinearSpace + LieGroup>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+    V::Dim: DimOfGL,
+{
+    pub origin: AffineSpace<V>,
+    pub basis: LinearBasis<V>,
+}
+
+impl<V: LinearSpace + LieGroup> AffineFrame<V>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+    V::Dim: DimOfGL,
+{
+    pub fn new(origin: AffineSpace<V>, basis: LinearBasis<V>) -> Self {
+        Self { origin, basis }
+    }
+}
+
+impl<V: LinearSpace + LieGroup> Chart<AffineSpace<V>> for AffineFrame<V>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+    V::Dim: DimOfGL + ToTypenum<Typenum: ArrayLength>,
+{
+    fn to_local(&self, point: &AffineSpace<V>) -> Components<AffineSpace<V>>
+    where
+        <AffineSpace<V> as Manifold>::Dim: ToTypenum<Typenum: ArrayLength>,
+    {
+        let displacement =
+            V::_from_raw(point._from_origin._get_raw() - self.origin._from_origin._get_raw());
+        self.basis.to_local(&displacement)
+    }
+
+    fn from_local(&self, components: &Components<AffineSpace<V>>) -> AffineSpace<V>
+    where
+        <AffineSpace<V> as Manifold>::Dim: ToTypenum<Typenum: ArrayLength>,
+    {
+        let displacement = self.basis.from_local(components);
+        Torsor {
+            _from_origin: V::_from_raw(
+                self.origin._from_origin._get_raw() + displacement._get_raw(),
+            ),
+        }
+    }
+}
+
+impl<V: InnerProductSpace + LieGroup> AffineFrame<V>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+    V::Dim: DimName + DimOfGL + DimOfSE + SERepr<V>,
+{
+    // Frame carried along by a rigid motion of the space, see `OrthonormalAffineFrame::act`
+    pub fn act(&self, motion: &SpecialEuclideanGroup<V>) -> Self {
+        let (rotation, _) = <V::Dim as SERepr<V>>::to_parts(&motion.repr);
+        let basis = &self.basis._from_origin;
+        Self {
+            origin: motion.act_on(&self.origin),
+            basis: Torsor {
+                _from_origin: GeneralLinearGroup {
+                    matrix: &basis.matrix * rotation.transpose(),
+                    inverse: rotation * &basis.inverse,
+                },
+            },
+        }
+    }
+}
+
+impl<V: InnerProductSpace + LieGroup> From<OrthonormalAffineFrame<V>> for AffineFrame<V>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+    V::Dim: DimName + DimOfGL + DimOfSE + DimOfSO + SERepr<V> + SORepr<V>,
+{
+    fn from(frame: OrthonormalAffineFrame<V>) -> Self {
+        let (rotation, _) = <V::Dim as SERepr<V>>::to_parts(&frame._from_origin.repr);
+        Self {
+            origin: frame.origin(),
+            basis: Torsor {
+                _from_origin: GeneralLinearGroup {
+                    inverse: rotation.transpose(),
+                    matrix: rotation,
+                },
+            },
+        }
+    }
+}
+
+// Affine frame with an orthonormal basis, as the rigid motion taking the reference components of
+// points to their components in the frame
+
+pub type OrthonormalAffineFrame<V> = Torsor<SpecialEuclideanGroup<V>>;
+
+impl<V: InnerProductSpace + LieGroup> Chart<AffineSpace<V>> for OrthonormalAffineFrame<V>
+where
+    DefaultAllocator: Allocator<V::Dim>,
+    V::Dim: DimName + DimOfSE + SERepr<V> + ToTypenum<Typenum: ArrayLength>,
+{
+    fn to_local(&self, point: &AffineSpace<V>) -> Components<AffineSpace<V>>
+    where
+        <AffineSpace<V> as Manifold>::Dim: ToTypenum<Typenum: ArrayLength>,
+    {
+        let local = self._from_origin.act_on(point);
+        GenericArray::from_slice(local._from_origin._get_raw().as_slice()).clone()
+    }
+
+    fn from_local(&self, components: &Components<AffineSpace<V>>) -> AffineSpace<V>
+    where
+        <AffineSpace<V> as Manifold>::Dim: ToTypenum<Typenum: ArrayLength>,
+    {
+        let local = Torsor {
+            _from_origin: V::_from_raw(OVector::from_column_slice(components.as_slice())),
+        };
+        self._from_origin.inverse().act_on(&local)
+    }
+}
+
+impl<V: InnerProductSpace + LieGroup> OrthonormalAffineFrame<V>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+    V::Dim: DimName + DimOfSE + DimOfSO + SERepr<V> + SORepr<V>,
+{
+    pub fn new(origin: &AffineSpace<V>, basis: &OrthonormalLinearBasis<V>) -> Self {
+        let rotation = <V::Dim as SORepr<V>>::to_matrix(&basis._from_origin.repr);
+        let translation = V::_from_raw(-(&rotation * origin._from_origin._get_raw()));
+        Torsor {
+            _from_origin: SpecialEuclideanGroup {
+                repr: <V::Dim as SERepr<V>>::from_parts(&rotation, &translation),
+            },
+        }
+    }
+
+    pub fn origin(&self) -> AffineSpace<V> {
+        let origin = Torsor {
+            _from_origin: V::zero(),
+        };
+        self._from_origin.inverse().act_on(&origin)
+    }
+
+    pub fn basis(&self) -> OrthonormalLinearBasis<V> {
+        let (rotation, _) = <V::Dim as SERepr<V>>::to_parts(&self._from_origin.repr);
+        Torsor {
+            _from_origin: SpecialOrthogonalGroup {
+                repr: <V::Dim as SORepr<V>>::from_matrix(&rotation),
+            },
+        }
+    }
+
+    // Frame carried along by a rigid motion of the space, in which the moved points have the
+    // components the points had before. Unlike `act_on`, which composes the motion with the
+    // components of the frame, this moves the frame itself.
+    pub fn act(&self, motion: &SpecialEuclideanGroup<V>) -> Self {
+        Torsor {
+            _from_origin: self._from_origin.multiply(&motion.inverse()),
+        }
+    }
+}
+
+impl<V: InnerProductSpace + LieGroup> AffineSpace<V>
+where
+    DefaultAllocator: Allocator<V::Dim>,
+    V::Dim: DimName + DimOfSE + SERepr<V>,
+{
+    // Frame whose components are the reference components
+    pub fn reference_frame() -> OrthonormalAffineFrame<V> {
+        Torsor {
+            _from_origin: SpecialEuclideanGroup::identity(),
+        }
+    }
+}
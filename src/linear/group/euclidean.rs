@@ -1,25 +1,47 @@
+use core::fmt::Debug;
+
+use approx::AbsDiffEq;
 use nalgebra::{
-    DimAdd, DimDiff, DimDiv, DimMul, DimName, DimProd, DimQuot, DimSub, DimSum, Rotation2, U1, U2,
-    U3, UnitDualQuaternion,
+    ComplexField, DimAdd, DimDiff, DimDiv, DimMul, DimName, DimProd, DimQuot, DimSub, DimSum,
+    Matrix2, Matrix3, OMatrix, Point3, RealField, Rotation2, Rotation3, Translation3, U1, U2, U3,
+    UnitDualQuaternion, UnitQuaternion, Vector2, Vector3, convert,
 };
 
-use crate::lie::LieGroup;
-use crate::linear::space::{Allocator, DefaultAllocator, InnerProductSpace};
+use crate::lie::{ExponentialMap, GroupAction, LieAlgebra, LieGroup, Torsor};
+use crate::linear::group::orthogonal::{SORepr, SpecialOrthogonalGroup};
+use crate::linear::space::{AffineSpace, Allocator, DefaultAllocator, InnerProductSpace};
 use crate::manifold::*;
 
 pub type SE<V> = SpecialEuclideanGroup<V>;
 
+type Matrix<V> = OMatrix<<V as Manifold>::Field, <V as Manifold>::Dim, <V as Manifold>::Dim>;
+
 pub trait SERepr<V: InnerProductSpace>
 where
     DefaultAllocator: Allocator<V::Dim>,
 {
-    type Repr;
+    type Repr: Clone + Debug;
     fn identity() -> Self::Repr;
     fn multiply(a: &Self::Repr, b: &Self::Repr) -> Self::Repr;
     fn inverse(a: &Self::Repr) -> Self::Repr;
+
+    fn act_on_vector(a: &Self::Repr, vector: &V) -> V;
+    fn act_on_point(a: &Self::Repr, point: &V) -> V;
+
+    // From a proper orthogonal matrix and a translation, acting on reference components
+    fn from_parts(rotation: &OMatrix<V::Field, V::Dim, V::Dim>, translation: &V) -> Self::Repr
+    where
+        DefaultAllocator: Allocator<V::Dim, V::Dim>;
+    fn to_parts(a: &Self::Repr) -> (Matrix<V>, V)
+    where
+        DefaultAllocator: Allocator<V::Dim, V::Dim>;
+
+    // From the components of an element of the Lie algebra, the angular part first and then the
+    // velocity of the point at the reference origin
+    fn exp(raw: &[V::Field]) -> Self::Repr;
 }
 
-impl<V: InnerProductSpace<Dim = U2>> SERepr<V> for U2
+impl<V: InnerProductSpace<Dim = U2> + Clone + Debug> SERepr<V> for U2
 where
     DefaultAllocator: Allocator<V::Dim>,
 {
@@ -41,9 +63,35 @@ where
         let rotation_matrix = Rotation2::new(-*theta);
         (V::_from_raw(rotation_matrix * -t._get_raw()), -*theta)
     }
+    fn act_on_vector(a: &Self::Repr, vector: &V) -> V {
+        V::_from_raw(Rotation2::new(a.1) * vector._get_raw())
+    }
+    fn act_on_point(a: &Self::Repr, point: &V) -> V {
+        V::_from_raw(Rotation2::new(a.1) * point._get_raw() + a.0._get_raw())
+    }
+    fn from_parts(rotation: &OMatrix<V::Field, U2, U2>, translation: &V) -> Self::Repr {
+        (
+            translation.clone(),
+            rotation[(1, 0)].atan2(rotation[(0, 0)]),
+        )
+    }
+    fn to_parts(a: &Self::Repr) -> (OMatrix<V::Field, U2, U2>, V) {
+        (Rotation2::new(a.1).into_inner(), a.0.clone())
+    }
+    fn exp(raw: &[V::Field]) -> Self::Repr {
+        let (angle, velocity) = (raw[0], Vector2::new(raw[1], raw[2]));
+        // sin(θ)/θ and (1 - cos(θ))/θ, by their series near zero
+        let (sinc, cosc) = if angle.abs() < V::Field::default_epsilon().sqrt() {
+            (V::Field::one(), angle * convert(0.5))
+        } else {
+            (angle.sin() / angle, (V::Field::one() - angle.cos()) / angle)
+        };
+        let translation = Matrix2::new(sinc, -cosc, cosc, sinc) * velocity;
+        (V::_from_raw(translation), angle)
+    }
 }
 
-impl<V: InnerProductSpace> SERepr<V> for U3
+impl<V: InnerProductSpace<Dim = U3>> SERepr<V> for U3
 where
     DefaultAllocator: Allocator<V::Dim>,
 {
@@ -57,6 +105,45 @@ where
     fn inverse(a: &Self::Repr) -> Self::Repr {
         a.inverse()
     }
+    fn act_on_vector(a: &Self::Repr, vector: &V) -> V {
+        V::_from_raw(a.transform_vector(vector._get_raw()))
+    }
+    fn act_on_point(a: &Self::Repr, point: &V) -> V {
+        V::_from_raw(a.transform_point(&Point3::from(*point._get_raw())).coords)
+    }
+    fn from_parts(rotation: &OMatrix<V::Field, U3, U3>, translation: &V) -> Self::Repr {
+        UnitDualQuaternion::from_parts(
+            Translation3::from(*translation._get_raw()),
+            UnitQuaternion::from_rotation_matrix(&Rotation3::from_matrix_unchecked(*rotation)),
+        )
+    }
+    fn exp(raw: &[V::Field]) -> Self::Repr {
+        let angular = Vector3::new(raw[0], raw[1], raw[2]);
+        let velocity = Vector3::new(raw[3], raw[4], raw[5]);
+        let angle = angular.norm();
+        let w = angular.cross_matrix();
+        // (1 - cos(θ))/θ² and (θ - sin(θ))/θ³, by their series near zero
+        let (a, b) = if angle < V::Field::default_epsilon().sqrt() {
+            (convert(0.5), convert(1.0 / 6.0))
+        } else {
+            let squared = angle * angle;
+            (
+                (V::Field::one() - angle.cos()) / squared,
+                (angle - angle.sin()) / (squared * angle),
+            )
+        };
+        let translation = (Matrix3::identity() + w * a + w * w * b) * velocity;
+        UnitDualQuaternion::from_parts(
+            Translation3::from(translation),
+            UnitQuaternion::from_scaled_axis(angular),
+        )
+    }
+    fn to_parts(a: &Self::Repr) -> (OMatrix<V::Field, U3, U3>, V) {
+        (
+            a.rotation().to_rotation_matrix().into_inner(),
+            V::_from_raw(a.translation().vector),
+        )
+    }
 }
 
 pub trait DimOfSE {
@@ -73,12 +160,13 @@ where
     type Dim = DimSum<DimQuot<DimProd<N, DimDiff<N, U1>>, U2>, N>;
 }
 
+#[derive(Clone, Debug)]
 pub struct SpecialEuclideanGroup<V: InnerProductSpace>
 where
     V::Dim: SERepr<V>,
     DefaultAllocator: Allocator<V::Dim>,
 {
-    repr: <V::Dim as SERepr<V>>::Repr,
+    pub(crate) repr: <V::Dim as SERepr<V>>::Repr,
 }
 
 impl<V: InnerProductSpace> Manifold for SpecialEuclideanGroup<V>
@@ -111,3 +199,83 @@ where
         }
     }
 }
+
+impl<V: InnerProductSpace> ExponentialMap for SpecialEuclideanGroup<V>
+where
+    V::Dim: SERepr<V> + DimOfSE,
+    DefaultAllocator: Allocator<V::Dim> + Allocator<<V::Dim as DimOfSE>::Dim>,
+{
+    fn exp(algebra: &LieAlgebra<Self>) -> Self {
+        Self {
+            repr: <V::Dim as SERepr<V>>::exp(algebra._get_raw().as_slice()),
+        }
+    }
+}
+
+impl<V: InnerProductSpace> GroupAction<V> for SpecialEuclideanGroup<V>
+where
+    V::Dim: SERepr<V> + DimOfSE,
+    DefaultAllocator: Allocator<V::Dim>,
+{
+    fn act_on(&self, vector: &V) -> V {
+        <V::Dim as SERepr<V>>::act_on_vector(&self.repr, vector)
+    }
+}
+
+impl<V: InnerProductSpace + LieGroup> GroupAction<AffineSpace<V>> for SpecialEuclideanGroup<V>
+where
+    V::Dim: SERepr<V> + DimOfSE,
+    DefaultAllocator: Allocator<V::Dim>,
+{
+    fn act_on(&self, point: &AffineSpace<V>) -> AffineSpace<V> {
+        Torsor {
+            _from_origin: <V::Dim as SERepr<V>>::act_on_point(&self.repr, &point._from_origin),
+        }
+    }
+}
+
+impl<V: InnerProductSpace + LieGroup> SpecialEuclideanGroup<V>
+where
+    V::Dim: DimName + SERepr<V> + SORepr<V>,
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+{
+    // Rotation about the reference origin followed by a translation
+    pub fn from_parts(rotation: &SpecialOrthogonalGroup<V>, translation: &V) -> Self {
+        let matrix = <V::Dim as SORepr<V>>::to_matrix(&rotation.repr);
+        Self {
+            repr: <V::Dim as SERepr<V>>::from_parts(&matrix, translation),
+        }
+    }
+
+    pub fn from_translation(translation: &V) -> Self {
+        Self {
+            repr: <V::Dim as SERepr<V>>::from_parts(
+                &OMatrix::<V::Field, V::Dim, V::Dim>::identity(),
+                translation,
+            ),
+        }
+    }
+
+    // Rotation about a center, which is left in place
+    pub fn from_rotation(rotation: &SpecialOrthogonalGroup<V>, center: &AffineSpace<V>) -> Self {
+        let matrix = <V::Dim as SORepr<V>>::to_matrix(&rotation.repr);
+        let center = center._from_origin._get_raw();
+        let translation = V::_from_raw(center - &matrix * center);
+        Self {
+            repr: <V::Dim as SERepr<V>>::from_parts(&matrix, &translation),
+        }
+    }
+
+    // Rotation of directions, which does not depend on the reference origin
+    pub fn rotation(&self) -> SpecialOrthogonalGroup<V> {
+        let (matrix, _) = <V::Dim as SERepr<V>>::to_parts(&self.repr);
+        SpecialOrthogonalGroup {
+            repr: <V::Dim as SORepr<V>>::from_matrix(&matrix),
+        }
+    }
+
+    // Displacement of the reference origin, completing `rotation` as in `from_parts`
+    pub fn translation(&self) -> V {
+        <V::Dim as SERepr<V>>::to_parts(&self.repr).1
+    }
+}
@@ -1,27 +1,80 @@
-use nalgebra::DimName;
-use nalgebra::{DimMul, DimProd, OMatrix};
+use nalgebra::{Dim, DimMul, DimName, DimProd, OMatrix};
 
+use crate::error::{Error, Result};
 use crate::lie::{GroupAction, LieGroup};
-use crate::linear::space::{Allocator, DefaultAllocator, LinearSpace};
-use crate::manifold::Manifold;
+use crate::linear::space::{Allocator, DefaultAllocator, LinearSpace, check_dim};
+use crate::manifold::{Manifold, Scalar};
 
 pub type GL<V> = GeneralLinearGroup<V>;
 
+#[derive(Clone, Debug)]
 pub struct GeneralLinearGroup<V: LinearSpace>
 where
     DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
 {
-    matrix: OMatrix<V::Field, V::Dim, V::Dim>,
+    pub(crate) matrix: OMatrix<V::Field, V::Dim, V::Dim>,
+    // Kept alongside the matrix so that inverting never factorizes
+    pub(crate) inverse: OMatrix<V::Field, V::Dim, V::Dim>,
+}
+
+impl<V: LinearSpace> GeneralLinearGroup<V>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+{
+    // Element acting by the matrix in reference components, failing with `Error::Singular`, or
+    // `Error::IllConditioned` when the inverse cannot be trusted. Since the inverse is computed
+    // here, inverting the element later never fails
+    pub fn try_from_matrix(matrix: OMatrix<V::Field, V::Dim, V::Dim>) -> Result<Self> {
+        let inverse = checked_inverse(&matrix)?;
+        Ok(Self { matrix, inverse })
+    }
+
+    // Without the conditioning check of `try_from_matrix`
+    pub(crate) fn from_invertible(matrix: OMatrix<V::Field, V::Dim, V::Dim>) -> Option<Self> {
+        let inverse = matrix.clone().try_inverse()?;
+        Some(Self { matrix, inverse })
+    }
+
+    // Identity of a runtime-sized space, or of a fixed-size space of that dimension
+    pub fn identity_with_dim(dim: usize) -> Result<Self> {
+        check_dim(V::Dim::try_to_usize().unwrap_or(dim), dim)?;
+        Ok(Self::identity_of(V::Dim::from_usize(dim)))
+    }
+
+    fn identity_of(dim: V::Dim) -> Self {
+        Self {
+            matrix: OMatrix::identity_generic(dim, dim),
+            inverse: OMatrix::identity_generic(dim, dim),
+        }
+    }
+
+    pub fn dim(&self) -> usize {
+        self.matrix.nrows()
+    }
+}
+
+// Inverse of a matrix, rejecting those whose condition number |A| |A^-1| (in the Frobenius norm)
+// exceeds the reciprocal of the square root of the machine epsilon
+pub(crate) fn checked_inverse<K: Scalar, N: Dim>(
+    matrix: &OMatrix<K, N, N>,
+) -> Result<OMatrix<K, N, N>>
+where
+    DefaultAllocator: Allocator<N, N>,
+{
+    let inverse = matrix.clone().try_inverse().ok_or(Error::Singular)?;
+    if matrix.norm() * inverse.norm() * K::default_epsilon().sqrt() > K::one() {
+        return Err(Error::IllConditioned);
+    }
+    Ok(inverse)
 }
 
 pub trait DimOfGL {
-    type Dim: DimName;
+    type Dim: Dim;
 }
 
-impl<N: DimName> DimOfGL for N
+impl<N: Dim> DimOfGL for N
 where
     N: DimMul<N>,
-    DimProd<N, N>: DimName,
 {
     type Dim = DimProd<N, N>;
 }
@@ -40,27 +93,32 @@ where
     V::Dim: DimOfGL,
     DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
 {
-    fn identity() -> Self {
-        Self {
-            matrix: OMatrix::<V::Field, V::Dim, V::Dim>::identity(),
-        }
+    fn identity() -> Self
+    where
+        Self::Dim: DimName,
+    {
+        // The group of an n-dimensional space has dimension n^2
+        Self::identity_of(V::Dim::from_usize(Self::Dim::dim().isqrt()))
     }
 
     fn multiply(&self, other: &Self) -> Self {
         Self {
             matrix: &self.matrix * &other.matrix,
+            inverse: &other.inverse * &self.inverse,
         }
     }
 
     fn inverse(&self) -> Self {
         Self {
-            matrix: self
-                .matrix
-                .clone()
-                .try_inverse()
-                .expect("Matrix is not invertible."),
+            matrix: self.inverse.clone(),
+            inverse: self.matrix.clone(),
         }
     }
+
+    fn try_multiply(&self, other: &Self) -> Result<Self> {
+        check_dim(self.dim(), other.dim())?;
+        Ok(self.multiply(other))
+    }
 }
 
 impl<V: LinearSpace> GroupAction<V> for GeneralLinearGroup<V>
@@ -71,4 +129,9 @@ where
     fn act_on(&self, point: &V) -> V {
         V::_from_raw(&self.matrix * point._get_raw())
     }
+
+    fn try_act_on(&self, point: &V) -> Result<V> {
+        check_dim(self.dim(), point.dim())?;
+        Ok(self.act_on(point))
+    }
 }
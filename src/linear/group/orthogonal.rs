@@ -1,9 +1,11 @@
-use crate::lie::{GroupAction, LieGroup};
-use crate::linear::space::{Allocator, DefaultAllocator, InnerProductSpace};
+use crate::error::{Error, Result};
+use crate::lie::{ExponentialMap, GroupAction, LieAlgebra, LieGroup, Torsor};
+use crate::linear::space::{AffineSpace, Allocator, DefaultAllocator, InnerProductSpace};
 use crate::manifold::*;
+use core::fmt::Debug;
 use nalgebra::{
-    DimDiff, DimDiv, DimMul, DimName, DimProd, DimQuot, DimSub, Rotation2, U1, U2, U3, Unit,
-    UnitQuaternion,
+    ComplexField, DimDiff, DimDiv, DimMul, DimName, DimProd, DimQuot, DimSub, OMatrix, RealField,
+    Rotation2, Rotation3, U1, U2, U3, Unit, UnitQuaternion, Vector3,
 };
 
 pub type SO<V> = SpecialOrthogonalGroup<V>;
@@ -12,13 +14,25 @@ pub trait SORepr<V: InnerProductSpace>
 where
     DefaultAllocator: Allocator<<V as Manifold>::Dim>,
 {
-    type Repr;
+    type Repr: Clone + Debug;
 
     fn identity() -> Self::Repr;
     fn multiply(a: &Self::Repr, b: &Self::Repr) -> Self::Repr;
     fn inverse(a: &Self::Repr) -> Self::Repr;
 
     fn act(a: &Self::Repr, point: &V) -> V;
+
+    // From a proper orthogonal matrix acting on reference components
+    fn from_matrix(matrix: &OMatrix<V::Field, V::Dim, V::Dim>) -> Self::Repr
+    where
+        DefaultAllocator: Allocator<V::Dim, V::Dim>;
+    fn to_matrix(a: &Self::Repr) -> OMatrix<V::Field, V::Dim, V::Dim>
+    where
+        DefaultAllocator: Allocator<V::Dim, V::Dim>;
+
+    // From the components of an element of the Lie algebra, the angle in 2D and the rotation
+    // vector in 3D
+    fn exp(raw: &[V::Field]) -> Self::Repr;
 }
 
 impl<V: InnerProductSpace<Dim = U2>> SORepr<V> for U2
@@ -38,6 +52,15 @@ where
     fn act(a: &Self::Repr, point: &V) -> V {
         V::_from_raw(Rotation2::new(*a) * point._get_raw())
     }
+    fn from_matrix(matrix: &OMatrix<V::Field, U2, U2>) -> Self::Repr {
+        matrix[(1, 0)].atan2(matrix[(0, 0)])
+    }
+    fn to_matrix(a: &Self::Repr) -> OMatrix<V::Field, U2, U2> {
+        Rotation2::new(*a).into_inner()
+    }
+    fn exp(raw: &[V::Field]) -> Self::Repr {
+        raw[0]
+    }
 }
 
 impl<V: InnerProductSpace<Dim = U3>> SORepr<V> for U3
@@ -58,14 +81,24 @@ where
     fn act(a: &Self::Repr, point: &V) -> V {
         V::_from_raw(a * point._get_raw())
     }
+    fn from_matrix(matrix: &OMatrix<V::Field, U3, U3>) -> Self::Repr {
+        UnitQuaternion::from_rotation_matrix(&Rotation3::from_matrix_unchecked(*matrix))
+    }
+    fn to_matrix(a: &Self::Repr) -> OMatrix<V::Field, U3, U3> {
+        a.to_rotation_matrix().into_inner()
+    }
+    fn exp(raw: &[V::Field]) -> Self::Repr {
+        UnitQuaternion::from_scaled_axis(Vector3::from_column_slice(raw))
+    }
 }
 
+#[derive(Clone, Debug)]
 pub struct SpecialOrthogonalGroup<V: InnerProductSpace>
 where
     V::Dim: SORepr<V>,
     DefaultAllocator: Allocator<V::Dim>,
 {
-    repr: <V::Dim as SORepr<V>>::Repr,
+    pub(crate) repr: <V::Dim as SORepr<V>>::Repr,
 }
 
 // pub type DimOfSO<N: DimName> = DimQuot<DimProd<N, DimDiff<N, U1>>, U2>;
@@ -114,6 +147,18 @@ where
     }
 }
 
+impl<V: InnerProductSpace> ExponentialMap for SpecialOrthogonalGroup<V>
+where
+    V::Dim: SORepr<V> + DimOfSO,
+    DefaultAllocator: Allocator<V::Dim> + Allocator<<V::Dim as DimOfSO>::Dim>,
+{
+    fn exp(algebra: &LieAlgebra<Self>) -> Self {
+        Self {
+            repr: <V::Dim as SORepr<V>>::exp(algebra._get_raw().as_slice()),
+        }
+    }
+}
+
 impl<V: InnerProductSpace> GroupAction<V> for SpecialOrthogonalGroup<V>
 where
     V::Dim: SORepr<V> + DimOfSO,
@@ -124,6 +169,19 @@ where
     }
 }
 
+// Rotation about the origin of the reference
+impl<V: InnerProductSpace + LieGroup> GroupAction<AffineSpace<V>> for SpecialOrthogonalGroup<V>
+where
+    V::Dim: SORepr<V> + DimOfSO,
+    DefaultAllocator: Allocator<V::Dim>,
+{
+    fn act_on(&self, point: &AffineSpace<V>) -> AffineSpace<V> {
+        Torsor {
+            _from_origin: <V::Dim as SORepr<V>>::act(&self.repr, &point._from_origin),
+        }
+    }
+}
+
 pub trait SpecialOrthogonalGroup2D<V: InnerProductSpace>
 where
     DefaultAllocator: Allocator<V::Dim>,
@@ -152,12 +210,25 @@ where
 
 impl<V: InnerProductSpace<Dim = U3>> SpecialOrthogonalGroup<V> {
     pub fn from_axis_angle(axis: &V, angle: V::Field) -> Self {
-        Self {
-            repr: UnitQuaternion::from_axis_angle(
-                &Unit::new_normalize(axis._get_raw().clone()),
-                angle,
-            ),
+        Self::try_from_axis_angle(axis, angle).expect("Rotation axis is zero")
+    }
+
+    // Only an exactly zero axis is rejected, since the length of an axis carries no scale to judge
+    // a short one against. Dividing by the largest component first keeps tiny axes from
+    // underflowing when normalized
+    pub fn try_from_axis_angle(axis: &V, angle: V::Field) -> Result<Self> {
+        let raw = axis._get_raw();
+        if !(raw.iter().all(|x| x.is_finite()) && angle.is_finite()) {
+            return Err(Error::NotFinite);
+        }
+        let largest = raw.amax();
+        if largest == V::Field::zero() {
+            return Err(Error::ZeroVector);
         }
+        let axis = Unit::new_normalize(raw / largest);
+        Ok(Self {
+            repr: UnitQuaternion::from_axis_angle(&axis, angle),
+        })
     }
 
     pub fn axis(&self) -> Option<V> {
@@ -0,0 +1,227 @@
+use core::marker::PhantomData;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use generic_array::ArrayLength;
+use nalgebra::{DimName, OMatrix, ToTypenum};
+
+use crate::error::{Error, Result};
+use crate::linear::basis::LinearChart;
+use crate::linear::group::general::checked_inverse;
+use crate::linear::group::{DimOfGL, GeneralLinearGroup};
+use crate::linear::space::{
+    Allocator, DefaultAllocator, InnerProductSpace, LinearSpace, MetricSpace,
+};
+
+// Linear map from V to W, stored as the matrix between reference components
+
+#[derive(Clone, Debug)]
+pub struct LinearMap<V: LinearSpace, W: LinearSpace<Field = V::Field>>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<W::Dim> + Allocator<W::Dim, V::Dim>,
+{
+    raw: OMatrix<V::Field, W::Dim, V::Dim>,
+    _spaces: PhantomData<(V, W)>,
+}
+
+impl<V: LinearSpace, W: LinearSpace<Field = V::Field>> LinearMap<V, W>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<W::Dim> + Allocator<W::Dim, V::Dim>,
+{
+    pub fn zero() -> Self
+    where
+        V::Dim: DimName,
+        W::Dim: DimName,
+    {
+        Self::_from_raw(OMatrix::<V::Field, W::Dim, V::Dim>::zeros())
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        Self::_from_raw(&self.raw + &other.raw)
+    }
+
+    pub fn scale(&self, scalar: V::Field) -> Self {
+        Self::_from_raw(&self.raw * scalar)
+    }
+
+    pub fn apply(&self, vector: &V) -> W {
+        W::_from_raw(&self.raw * vector._get_raw())
+    }
+
+    // self ∘ other
+    pub fn compose<U: LinearSpace<Field = V::Field>>(
+        &self,
+        other: &LinearMap<U, V>,
+    ) -> LinearMap<U, W>
+    where
+        DefaultAllocator: Allocator<U::Dim> + Allocator<V::Dim, U::Dim> + Allocator<W::Dim, U::Dim>,
+    {
+        LinearMap::_from_raw(&self.raw * &other.raw)
+    }
+
+    // Pullback of covectors, (A^T α)(v) = α(A v)
+    pub fn transpose(&self) -> LinearMap<W::DualSpace, V::DualSpace>
+    where
+        DefaultAllocator: Allocator<V::Dim, W::Dim>,
+    {
+        LinearMap::_from_raw(self.raw.transpose())
+    }
+
+    // Matrix in the given bases, in row-major order
+    #[cfg(feature = "alloc")]
+    pub fn to_local<C: LinearChart<V>, D: LinearChart<W>>(
+        &self,
+        domain: &C,
+        codomain: &D,
+    ) -> Vec<V::Field>
+    where
+        DefaultAllocator:
+            Allocator<V::Dim, V::Dim> + Allocator<W::Dim, W::Dim> + Allocator<V::Dim, W::Dim>,
+        V::Dim: ToTypenum<Typenum: ArrayLength>,
+        W::Dim: ToTypenum<Typenum: ArrayLength>,
+    {
+        let local = codomain._to_local_matrix() * &self.raw * domain._from_local_matrix();
+        local.transpose().as_slice().to_vec()
+    }
+
+    pub fn from_local<C: LinearChart<V>, D: LinearChart<W>>(
+        domain: &C,
+        codomain: &D,
+        components: &[V::Field],
+    ) -> Self
+    where
+        DefaultAllocator: Allocator<V::Dim, V::Dim> + Allocator<W::Dim, W::Dim>,
+        V::Dim: DimName + ToTypenum<Typenum: ArrayLength>,
+        W::Dim: DimName + ToTypenum<Typenum: ArrayLength>,
+    {
+        Self::try_from_local(domain, codomain, components).expect("Dimensions do not match")
+    }
+
+    pub fn try_from_local<C: LinearChart<V>, D: LinearChart<W>>(
+        domain: &C,
+        codomain: &D,
+        components: &[V::Field],
+    ) -> Result<Self>
+    where
+        DefaultAllocator: Allocator<V::Dim, V::Dim> + Allocator<W::Dim, W::Dim>,
+        V::Dim: DimName + ToTypenum<Typenum: ArrayLength>,
+        W::Dim: DimName + ToTypenum<Typenum: ArrayLength>,
+    {
+        if components.len() != V::Dim::dim() * W::Dim::dim() {
+            return Err(Error::DimensionMismatch);
+        }
+        let local = OMatrix::<V::Field, W::Dim, V::Dim>::from_row_slice(components);
+        Ok(Self::_from_raw(
+            codomain._from_local_matrix() * local * domain._to_local_matrix(),
+        ))
+    }
+
+    pub fn _get_raw(&self) -> &OMatrix<V::Field, W::Dim, V::Dim> {
+        &self.raw
+    }
+    pub fn _from_raw(raw: OMatrix<V::Field, W::Dim, V::Dim>) -> Self {
+        Self {
+            raw,
+            _spaces: PhantomData,
+        }
+    }
+}
+
+// Invertible maps are only possible between spaces of equal dimension
+
+impl<V: LinearSpace, W: LinearSpace<Field = V::Field, Dim = V::Dim>> LinearMap<V, W>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+{
+    // Inverse of any map that is not exactly singular, however close to singular it is
+    pub fn inverse(&self) -> Option<LinearMap<W, V>> {
+        Some(LinearMap::_from_raw(self.raw.clone().try_inverse()?))
+    }
+
+    // Inverse that is also rejected with `Error::IllConditioned` when the condition number exceeds
+    // the reciprocal of the square root of the machine epsilon
+    pub fn try_inverse(&self) -> Result<LinearMap<W, V>> {
+        Ok(LinearMap::_from_raw(checked_inverse(&self.raw)?))
+    }
+}
+
+impl<V: MetricSpace, W: MetricSpace<Field = V::Field>> LinearMap<V, W>
+where
+    DefaultAllocator: Allocator<V::Dim>
+        + Allocator<W::Dim>
+        + Allocator<W::Dim, V::Dim>
+        + Allocator<V::Dim, W::Dim>
+        + Allocator<V::Dim, V::Dim>
+        + Allocator<W::Dim, W::Dim>,
+{
+    // Adjoint with respect to the metrics, <A v, w> = <v, A* w>, which fails when the metric of the
+    // domain is degenerate
+    pub fn try_adjoint(&self) -> Result<LinearMap<W, V>>
+    where
+        V::Dim: DimName,
+        W::Dim: DimName,
+    {
+        let inverse = V::_gram_matrix().try_inverse().ok_or(Error::Singular)?;
+        Ok(LinearMap::_from_raw(
+            inverse * self.raw.transpose() * W::_gram_matrix(),
+        ))
+    }
+}
+
+impl<V: InnerProductSpace, W: InnerProductSpace<Field = V::Field>> LinearMap<V, W>
+where
+    DefaultAllocator: Allocator<V::Dim>
+        + Allocator<W::Dim>
+        + Allocator<W::Dim, V::Dim>
+        + Allocator<V::Dim, W::Dim>
+        + Allocator<V::Dim, V::Dim>
+        + Allocator<W::Dim, W::Dim>,
+{
+    pub fn adjoint(&self) -> LinearMap<W, V>
+    where
+        V::Dim: DimName,
+        W::Dim: DimName,
+    {
+        self.try_adjoint()
+            .expect("Inner products are nondegenerate")
+    }
+}
+
+impl<V: LinearSpace> LinearMap<V, V>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+{
+    pub fn identity() -> Self
+    where
+        V::Dim: DimName,
+    {
+        Self::_from_raw(OMatrix::<V::Field, V::Dim, V::Dim>::identity())
+    }
+
+    // Group element of any map that is not exactly singular, like `inverse`
+    pub fn to_group(&self) -> Option<GeneralLinearGroup<V>>
+    where
+        V::Dim: DimOfGL,
+    {
+        GeneralLinearGroup::from_invertible(self.raw.clone())
+    }
+
+    // Group element of a well-conditioned map, like `try_inverse`
+    pub fn try_to_group(&self) -> Result<GeneralLinearGroup<V>>
+    where
+        V::Dim: DimOfGL,
+    {
+        GeneralLinearGroup::try_from_matrix(self.raw.clone())
+    }
+}
+
+impl<V: LinearSpace> From<GeneralLinearGroup<V>> for LinearMap<V, V>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+    V::Dim: DimOfGL,
+{
+    fn from(group: GeneralLinearGroup<V>) -> Self {
+        Self::_from_raw(group.matrix)
+    }
+}
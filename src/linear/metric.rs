@@ -0,0 +1,180 @@
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+use nalgebra::{DimName, OMatrix, OVector, U1};
+
+use crate::lie::LieGroup;
+use crate::linear::space::{
+    Allocator, DefaultAllocator, InnerProductSpace, LinearSpace, MetricSpace,
+};
+use crate::manifold::{Manifold, Scalar};
+
+// Symmetric, possibly indefinite, bilinear form given by its Gram matrix in reference components.
+// The metric is a type, so that vectors with different metrics cannot be mixed, and its Gram
+// matrix is fixed at compile time: metrics read at runtime or varying from point to point, as on
+// a curved chart, cannot be expressed this way.
+
+pub trait Metric<N: DimName, K: Scalar>: Clone + Debug
+where
+    DefaultAllocator: Allocator<N, N>,
+{
+    fn gram() -> OMatrix<K, N, N>;
+}
+
+// Metrics whose Gram matrix is positive-definite, which makes their vectors an inner product space
+pub trait PositiveDefinite<N: DimName, K: Scalar>: Metric<N, K>
+where
+    DefaultAllocator: Allocator<N, N>,
+{
+}
+
+#[derive(Clone, Debug)]
+pub struct EuclideanMetric;
+
+impl<N: DimName, K: Scalar> Metric<N, K> for EuclideanMetric
+where
+    DefaultAllocator: Allocator<N, N>,
+{
+    fn gram() -> OMatrix<K, N, N> {
+        OMatrix::<K, N, N>::identity()
+    }
+}
+
+impl<N: DimName, K: Scalar> PositiveDefinite<N, K> for EuclideanMetric where
+    DefaultAllocator: Allocator<N, N>
+{
+}
+
+// Signature (-, +, ..., +), with the first reference component timelike
+#[derive(Clone, Debug)]
+pub struct MinkowskiMetric;
+
+impl<N: DimName, K: Scalar> Metric<N, K> for MinkowskiMetric
+where
+    DefaultAllocator: Allocator<N, N>,
+{
+    fn gram() -> OMatrix<K, N, N> {
+        OMatrix::<K, N, N>::from_fn(|i, j| match (i, j) {
+            (0, 0) => -K::one(),
+            _ if i == j => K::one(),
+            _ => K::zero(),
+        })
+    }
+}
+
+// Vector space whose inner product is given by the metric G
+
+#[derive(Clone, Debug)]
+pub struct MetricVector<N: DimName, G: Metric<N, K>, K: Scalar = f64>
+where
+    DefaultAllocator: Allocator<N, U1> + Allocator<N, N>,
+{
+    raw: OVector<K, N>,
+    _metric: PhantomData<G>,
+}
+
+impl<N: DimName, G: Metric<N, K>, K: Scalar> Manifold for MetricVector<N, G, K>
+where
+    DefaultAllocator: Allocator<N, U1> + Allocator<N, N>,
+{
+    type Field = K;
+    type Dim = N;
+}
+impl<N: DimName, G: Metric<N, K>, K: Scalar> LinearSpace for MetricVector<N, G, K>
+where
+    DefaultAllocator: Allocator<N, U1> + Allocator<N, N>,
+{
+    type DualSpace = MetricCovector<N, G, K>;
+
+    fn _get_raw(&self) -> &OVector<K, N> {
+        &self.raw
+    }
+
+    fn _from_raw(raw: OVector<K, N>) -> Self {
+        Self {
+            raw,
+            _metric: PhantomData,
+        }
+    }
+}
+impl<N: DimName, G: Metric<N, K>, K: Scalar> LieGroup for MetricVector<N, G, K>
+where
+    DefaultAllocator: Allocator<N, U1> + Allocator<N, N>,
+{
+    fn identity() -> Self {
+        Self::zero()
+    }
+    fn multiply(&self, other: &Self) -> Self {
+        Self::_from_raw(&self.raw + &other.raw)
+    }
+    fn inverse(&self) -> Self {
+        Self::_from_raw(-&self.raw)
+    }
+}
+impl<N: DimName, G: Metric<N, K>, K: Scalar> MetricSpace for MetricVector<N, G, K>
+where
+    DefaultAllocator: Allocator<N, U1> + Allocator<N, N>,
+{
+    fn dot(&self, other: &Self) -> K {
+        self.raw.dot(&(G::gram() * &other.raw))
+    }
+
+    fn _gram_matrix() -> OMatrix<K, N, N> {
+        G::gram()
+    }
+}
+
+/// Only positive-definite metrics give inner product spaces, so that Minkowski vectors cannot
+/// build an orthonormal basis:
+///
+/// ```compile_fail
+/// use geometrica::euclidean::U3;
+/// use geometrica::linear::basis::OrthonormalLinearBasis;
+/// use geometrica::linear::metric::MinkowskiVector;
+/// use geometrica::linear::LinearSpace;
+/// let t = MinkowskiVector::<U3>::_from_raw([1.0, 0.0, 0.0].into());
+/// let x = MinkowskiVector::<U3>::_from_raw([0.0, 1.0, 0.0].into());
+/// let _ = OrthonormalLinearBasis::from_axes(&[(0, &t), (1, &x)]);
+/// ```
+impl<N: DimName, G: PositiveDefinite<N, K>, K: Scalar> InnerProductSpace for MetricVector<N, G, K> where
+    DefaultAllocator: Allocator<N, U1> + Allocator<N, N>
+{
+}
+
+#[derive(Clone, Debug)]
+pub struct MetricCovector<N: DimName, G: Metric<N, K>, K: Scalar = f64>
+where
+    DefaultAllocator: Allocator<N, U1> + Allocator<N, N>,
+{
+    raw: OVector<K, N>,
+    _metric: PhantomData<G>,
+}
+
+impl<N: DimName, G: Metric<N, K>, K: Scalar> Manifold for MetricCovector<N, G, K>
+where
+    DefaultAllocator: Allocator<N, U1> + Allocator<N, N>,
+{
+    type Field = K;
+    type Dim = N;
+}
+
+impl<N: DimName, G: Metric<N, K>, K: Scalar> LinearSpace for MetricCovector<N, G, K>
+where
+    DefaultAllocator: Allocator<N, U1> + Allocator<N, N>,
+{
+    type DualSpace = MetricVector<N, G, K>;
+
+    fn _get_raw(&self) -> &OVector<K, N> {
+        &self.raw
+    }
+
+    fn _from_raw(raw: OVector<K, N>) -> Self {
+        Self {
+            raw,
+            _metric: PhantomData,
+        }
+    }
+}
+
+pub type MinkowskiVector<N, K = f64> = MetricVector<N, MinkowskiMetric, K>;
+pub type MinkowskiCovector<N, K = f64> = MetricCovector<N, MinkowskiMetric, K>;
@@ -1,40 +1,81 @@
-use crate::lie::Torsor;
-use crate::manifold::Manifold;
+use core::ops::{Add, Sub};
+
+use crate::error::{Error, Result};
+use crate::lie::{LieGroup, Torsor};
+use crate::linear::group::general::checked_inverse;
+use crate::manifold::{Manifold, One, Zero};
 use generic_array::{ArrayLength, GenericArray, sequence::GenericSequence};
-use nalgebra::{OMatrix, OVector, U1};
+use nalgebra::{Dim, DimName, OMatrix, OVector, ToTypenum, U1};
 
 pub use nalgebra::{DefaultAllocator, allocator::Allocator};
 
+// Basis given as an array of vectors, one per dimension
+pub type Basis<V> = GenericArray<V, <<V as Manifold>::Dim as ToTypenum>::Typenum>;
+
 pub trait LinearSpace: Manifold
 where
     DefaultAllocator: Allocator<Self::Dim, U1>,
 {
     type DualSpace: LinearSpace<DualSpace = Self, Field = Self::Field, Dim = Self::Dim>;
 
-    fn zero() -> Self {
+    fn zero() -> Self
+    where
+        Self::Dim: DimName,
+    {
         Self::_from_raw(OVector::zeros())
     }
+    // Zero vector of a runtime-sized space, or of a fixed-size space of that dimension
+    fn zero_with_dim(dim: usize) -> Result<Self> {
+        check_dim(Self::Dim::try_to_usize().unwrap_or(dim), dim)?;
+        Ok(Self::_from_raw(OVector::zeros_generic(
+            Self::Dim::from_usize(dim),
+            U1,
+        )))
+    }
+    fn dim(&self) -> usize {
+        self._get_raw().len()
+    }
     fn scale(&self, scalar: <Self as Manifold>::Field) -> Self {
         Self::_from_raw(self._get_raw() * scalar)
     }
 
     fn pair_with(&self, dual: &Self::DualSpace) -> <Self as Manifold>::Field {
-        self._get_raw().dot(&dual._get_raw())
+        self._get_raw().dot(dual._get_raw())
+    }
+
+    // Operations on runtime-sized values, failing instead of panicking when the sizes differ
+    fn try_pair_with(&self, dual: &Self::DualSpace) -> Result<<Self as Manifold>::Field> {
+        check_dim(self.dim(), dual.dim())?;
+        Ok(self.pair_with(dual))
+    }
+    fn try_add(&self, other: &Self) -> Result<Self> {
+        check_dim(self.dim(), other.dim())?;
+        Ok(Self::_from_raw(self._get_raw() + other._get_raw()))
+    }
+    fn try_sub(&self, other: &Self) -> Result<Self> {
+        check_dim(self.dim(), other.dim())?;
+        Ok(Self::_from_raw(self._get_raw() - other._get_raw()))
     }
-    fn dual_basis(basis: &GenericArray<Self, Self::Dim>) -> GenericArray<Self::DualSpace, Self::Dim>
+    fn dual_basis(basis: &Basis<Self>) -> Basis<Self::DualSpace>
     where
-        Self::Dim: ArrayLength,
+        Self::Dim: DimName + ToTypenum<Typenum: ArrayLength>,
         DefaultAllocator: Allocator<Self::Dim, Self::Dim>,
     {
-        let raws: GenericArray<OVector<Self::Field, Self::Dim>, Self::Dim> =
-            GenericArray::generate(|i| basis[i]._get_raw().clone());
-        let matrix = OMatrix::<Self::Field, Self::Dim, Self::Dim>::from_columns(&raws);
-        let inverse = matrix.try_inverse().expect("Basis is singular");
+        Self::try_dual_basis(basis).expect("Basis is singular")
+    }
+    fn try_dual_basis(basis: &Basis<Self>) -> Result<Basis<Self::DualSpace>>
+    where
+        Self::Dim: DimName + ToTypenum<Typenum: ArrayLength>,
+        DefaultAllocator: Allocator<Self::Dim, Self::Dim>,
+    {
+        let matrix =
+            OMatrix::<Self::Field, Self::Dim, Self::Dim>::from_fn(|i, j| basis[j]._get_raw()[i]);
+        let inverse = checked_inverse(&matrix)?;
 
         let inverse_t = inverse.transpose();
-        GenericArray::<Self::DualSpace, Self::Dim>::generate(|i| {
+        Ok(GenericArray::generate(|i| {
             Self::DualSpace::_from_raw(inverse_t.column(i).into_owned())
-        })
+        }))
     }
 
     // fn _induced_basis(basis: &Basis<N, Self>) -> [na::SVector<<Self as Manifold<N>>::Field, N>; N];
@@ -43,11 +84,152 @@ where
     fn _from_raw(raw: OVector<Self::Field, Self::Dim>) -> Self;
 }
 
-pub trait InnerProductSpace: LinearSpace
+// Space with a symmetric bilinear form, which may be indefinite as in Minkowski space, so that
+// squared lengths can be zero or negative and there are no orthonormal bases in general
+
+pub trait MetricSpace: LinearSpace
 where
     DefaultAllocator: Allocator<Self::Dim>,
 {
     fn dot(&self, other: &Self) -> Self::Field;
+
+    fn try_dot(&self, other: &Self) -> Result<Self::Field> {
+        check_dim(self.dim(), other.dim())?;
+        Ok(self.dot(other))
+    }
+
+    // Gram matrix of the metric in reference components
+    fn _gram_matrix() -> OMatrix<Self::Field, Self::Dim, Self::Dim>
+    where
+        Self::Dim: DimName,
+        DefaultAllocator: Allocator<Self::Dim, Self::Dim>,
+    {
+        let unit = |i: usize| {
+            Self::_from_raw(OVector::from_fn(|j, _| {
+                if i == j {
+                    Self::Field::one()
+                } else {
+                    Self::Field::zero()
+                }
+            }))
+        };
+        OMatrix::<Self::Field, Self::Dim, Self::Dim>::from_fn(|i, j| unit(i).dot(&unit(j)))
+    }
+
+    // Squared length, which may be zero or negative for indefinite metrics
+    fn norm_squared(&self) -> Self::Field {
+        self.dot(self)
+    }
+
+    // Musical isomorphisms: flat takes v to <v, .>, and sharp is its inverse, which only exists
+    // for a nondegenerate metric
+    fn flat(&self) -> Self::DualSpace
+    where
+        Self::Dim: DimName,
+        DefaultAllocator: Allocator<Self::Dim, Self::Dim>,
+    {
+        Self::DualSpace::_from_raw(Self::_gram_matrix().tr_mul(self._get_raw()))
+    }
+
+    fn sharp(covector: &Self::DualSpace) -> Result<Self>
+    where
+        Self::Dim: DimName,
+        DefaultAllocator: Allocator<Self::Dim, Self::Dim>,
+    {
+        let inverse = Self::_gram_matrix().try_inverse().ok_or(Error::Singular)?;
+        Ok(Self::_from_raw(inverse.tr_mul(covector._get_raw())))
+    }
 }
 
-pub type AffineSpace<V: LinearSpace> = Torsor<V>;
+// Metric space whose metric is positive-definite, which rotations, orthonormal bases and lengths
+// rely on
+
+pub trait InnerProductSpace: MetricSpace
+where
+    DefaultAllocator: Allocator<Self::Dim>,
+{
+}
+
+pub(crate) fn check_dim(dim: usize, other: usize) -> Result<()> {
+    if dim != other {
+        return Err(Error::DimensionMismatch);
+    }
+    Ok(())
+}
+
+// Points of an affine space only support the affine operations: point + vector and
+// point - vector give points, and point - point gives the vector between them.
+
+/// Adding two points is rejected at compile time:
+///
+/// ```compile_fail
+/// use geometrica::euclidean::*;
+/// let p = EuclideanSpace::<U3> { _from_origin: Vector::zero() };
+/// let _ = &p + &p;
+/// ```
+pub type AffineSpace<V> = Torsor<V>;
+
+impl<V: LinearSpace + LieGroup> Add<V> for Torsor<V>
+where
+    DefaultAllocator: Allocator<V::Dim>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: V) -> Self {
+        &self + &rhs
+    }
+}
+impl<V: LinearSpace + LieGroup> Add<&V> for &Torsor<V>
+where
+    DefaultAllocator: Allocator<V::Dim>,
+{
+    type Output = Torsor<V>;
+
+    fn add(self, rhs: &V) -> Torsor<V> {
+        Torsor {
+            _from_origin: rhs.multiply(&self._from_origin),
+        }
+    }
+}
+impl<V: LinearSpace + LieGroup> Sub<V> for Torsor<V>
+where
+    DefaultAllocator: Allocator<V::Dim>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: V) -> Self {
+        &self - &rhs
+    }
+}
+impl<V: LinearSpace + LieGroup> Sub<&V> for &Torsor<V>
+where
+    DefaultAllocator: Allocator<V::Dim>,
+{
+    type Output = Torsor<V>;
+
+    fn sub(self, rhs: &V) -> Torsor<V> {
+        Torsor {
+            _from_origin: rhs.inverse().multiply(&self._from_origin),
+        }
+    }
+}
+impl<V: LinearSpace + LieGroup> Sub for Torsor<V>
+where
+    DefaultAllocator: Allocator<V::Dim>,
+{
+    type Output = V;
+
+    fn sub(self, rhs: Self) -> V {
+        &self - &rhs
+    }
+}
+impl<V: LinearSpace + LieGroup> Sub for &Torsor<V>
+where
+    DefaultAllocator: Allocator<V::Dim>,
+{
+    type Output = V;
+
+    fn sub(self, rhs: Self) -> V {
+        self._from_origin.multiply(&rhs._from_origin.inverse())
+    }
+}
@@ -0,0 +1,309 @@
+use alloc::vec::Vec;
+use approx::AbsDiffEq;
+use nalgebra::{ComplexField, DMatrix, DVector, DimName, OVector, RealField};
+
+use crate::error::{Error, Result};
+use crate::lie::{GroupAction, LieGroup, Torsor};
+use crate::linear::basis::unit;
+use crate::linear::space::{AffineSpace, Allocator, DefaultAllocator, InnerProductSpace};
+use crate::manifold::{One, Zero, total_cmp};
+
+// Linear subspace of an inner product space, stored as an orthonormal basis of the subspace.
+// The metric is assumed to be positive-definite.
+
+#[derive(Clone, Debug)]
+pub struct LinearSubspace<V: InnerProductSpace + Clone>
+where
+    DefaultAllocator: Allocator<V::Dim>,
+{
+    basis: Vec<V>,
+}
+
+impl<V: InnerProductSpace + Clone> LinearSubspace<V>
+where
+    V::Dim: DimName,
+    DefaultAllocator: Allocator<V::Dim>,
+{
+    pub fn zero() -> Self {
+        Self { basis: Vec::new() }
+    }
+
+    pub fn full() -> Self {
+        Self::zero().complement()
+    }
+
+    // Span of the vectors, which may be linearly dependent
+    pub fn span(vectors: &[V]) -> Self {
+        let mut basis = Vec::new();
+        for vector in vectors {
+            if let Some(e) = orthonormalize(&basis, vector) {
+                basis.push(e);
+            }
+        }
+        Self { basis }
+    }
+
+    // Subspace with the vectors as a basis, rejecting zero or linearly dependent vectors
+    pub fn try_from_basis(vectors: &[V]) -> Result<Self> {
+        let mut basis = Vec::new();
+        for vector in vectors {
+            if vector.dot(vector) == V::Field::zero() {
+                return Err(Error::ZeroVector);
+            }
+            basis.push(orthonormalize(&basis, vector).ok_or(Error::Degenerate)?);
+        }
+        Ok(Self { basis })
+    }
+
+    // Common kernel of the covectors, such as the plane with a given normal
+    pub fn from_normals(normals: &[V::DualSpace]) -> Self
+    where
+        DefaultAllocator: Allocator<V::Dim, V::Dim>,
+    {
+        Self::try_from_normals(normals).expect("Inner products are nondegenerate")
+    }
+
+    pub fn try_from_normals(normals: &[V::DualSpace]) -> Result<Self>
+    where
+        DefaultAllocator: Allocator<V::Dim, V::Dim>,
+    {
+        let normals = normals.iter().map(V::sharp).collect::<Result<Vec<V>>>()?;
+        Ok(Self::span(&normals).complement())
+    }
+
+    pub fn dim(&self) -> usize {
+        self.basis.len()
+    }
+
+    // Orthonormal basis of the subspace
+    pub fn basis(&self) -> &[V] {
+        &self.basis
+    }
+
+    pub fn project(&self, vector: &V) -> V {
+        let raw = self
+            .basis
+            .iter()
+            .fold(OVector::zeros(), |raw: OVector<V::Field, V::Dim>, e| {
+                raw + e._get_raw() * e.dot(vector)
+            });
+        V::_from_raw(raw)
+    }
+
+    // Component of the vector orthogonal to the subspace
+    pub fn reject(&self, vector: &V) -> V {
+        V::_from_raw(vector._get_raw() - self.project(vector)._get_raw())
+    }
+
+    pub fn distance(&self, vector: &V) -> V::Field {
+        let rejection = self.reject(vector);
+        rejection.dot(&rejection).sqrt()
+    }
+
+    pub fn contains(&self, vector: &V) -> bool {
+        self.distance(vector) <= tolerance::<V>() * (V::Field::one() + vector.dot(vector).sqrt())
+    }
+
+    pub fn complement(&self) -> Self {
+        let mut basis = self.basis.clone();
+        for i in 0..V::Dim::dim() {
+            if let Some(e) = orthonormalize(&basis, &V::_from_raw(unit(V::Dim::name(), i))) {
+                basis.push(e);
+            }
+        }
+        Self {
+            basis: basis.split_off(self.dim()),
+        }
+    }
+
+    pub fn sum(&self, other: &Self) -> Self {
+        Self::span(&[self.basis.as_slice(), other.basis.as_slice()].concat())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.complement().sum(&other.complement()).complement()
+    }
+
+    // Principal angles between the subspaces in ascending order, one for each dimension of the
+    // smaller subspace
+    pub fn principal_angles(&self, other: &Self) -> Vec<V::Field> {
+        if self.dim() == 0 || other.dim() == 0 {
+            return Vec::new();
+        }
+        let overlap = DMatrix::from_fn(self.dim(), other.dim(), |i, j| {
+            self.basis[i].dot(&other.basis[j])
+        });
+        let mut angles: Vec<V::Field> = overlap
+            .singular_values()
+            .iter()
+            .map(|s| RealField::min(*s, V::Field::one()).acos())
+            .collect();
+        angles.sort_by(total_cmp);
+        angles
+    }
+
+    // Largest principal angle, such as the angle between two lines, between a line and a plane,
+    // or the dihedral angle between two planes in 3D
+    pub fn angle(&self, other: &Self) -> V::Field {
+        self.principal_angles(other)
+            .into_iter()
+            .fold(V::Field::zero(), RealField::max)
+    }
+
+    pub fn act<G: GroupAction<V>>(&self, g: &G) -> Self {
+        let vectors: Vec<V> = self.basis.iter().map(|e| g.act_on(e)).collect();
+        Self::span(&vectors)
+    }
+}
+
+// Affine subspace of an affine space, a point together with a linear subspace of directions
+
+#[derive(Clone, Debug)]
+pub struct AffineSubspace<V: InnerProductSpace + LieGroup + Clone>
+where
+    DefaultAllocator: Allocator<V::Dim>,
+{
+    point: AffineSpace<V>,
+    direction: LinearSubspace<V>,
+}
+
+impl<V: InnerProductSpace + LieGroup + Clone> AffineSubspace<V>
+where
+    V::Dim: DimName,
+    DefaultAllocator: Allocator<V::Dim>,
+{
+    pub fn new(point: AffineSpace<V>, direction: LinearSubspace<V>) -> Self {
+        // Keep the point closest to the origin, so that equal subspaces have equal points
+        let offset = direction.reject(&point._from_origin);
+        Self {
+            point: Torsor {
+                _from_origin: offset,
+            },
+            direction,
+        }
+    }
+
+    pub fn line(point: AffineSpace<V>, direction: &V) -> Result<Self> {
+        Ok(Self::new(
+            point,
+            LinearSubspace::try_from_basis(core::slice::from_ref(direction))?,
+        ))
+    }
+
+    // Hyperplane through the point with the given normal covector
+    pub fn hyperplane(point: AffineSpace<V>, normal: &V::DualSpace) -> Result<Self>
+    where
+        DefaultAllocator: Allocator<V::Dim, V::Dim>,
+    {
+        let direction = LinearSubspace::try_from_normals(core::slice::from_ref(normal))?;
+        if direction.dim() == V::Dim::dim() {
+            return Err(Error::ZeroVector);
+        }
+        Ok(Self::new(point, direction))
+    }
+
+    // Smallest affine subspace through the points
+    pub fn through(points: &[AffineSpace<V>]) -> Result<Self> {
+        let (first, rest) = points.split_first().ok_or(Error::Empty)?;
+        let differences: Vec<V> = rest.iter().map(|p| p - first).collect();
+        Ok(Self::new(first.clone(), LinearSubspace::span(&differences)))
+    }
+
+    pub fn point(&self) -> &AffineSpace<V> {
+        &self.point
+    }
+
+    pub fn direction(&self) -> &LinearSubspace<V> {
+        &self.direction
+    }
+
+    pub fn dim(&self) -> usize {
+        self.direction.dim()
+    }
+
+    pub fn project(&self, point: &AffineSpace<V>) -> AffineSpace<V> {
+        &self.point + &self.direction.project(&(point - &self.point))
+    }
+
+    pub fn distance(&self, point: &AffineSpace<V>) -> V::Field {
+        self.direction.distance(&(point - &self.point))
+    }
+
+    pub fn contains(&self, point: &AffineSpace<V>) -> bool {
+        self.direction.contains(&(point - &self.point))
+    }
+
+    // Intersection, or None if the subspaces do not meet
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        // Solve p + u = q + w for u in D and w in E, by making the part of p - q + u orthogonal
+        // to E vanish
+        let offset = &self.point - &other.point;
+        let n = V::Dim::dim();
+        let k = self.dim();
+        let reject =
+            |v: &V| DVector::from_column_slice(other.direction.reject(v)._get_raw().as_slice());
+
+        let columns: Vec<DVector<V::Field>> = self.direction.basis.iter().map(reject).collect();
+        let system = DMatrix::from_fn(n, k, |i, j| columns[j][i]);
+        let rhs = -reject(&offset);
+        let coefficients = if k == 0 {
+            DVector::zeros(0)
+        } else {
+            system
+                .clone()
+                .svd(true, true)
+                .solve(&rhs, tolerance::<V>())
+                .ok()?
+        };
+        let residual = &system * &coefficients - &rhs;
+        let scale = V::Field::one() + offset.dot(&offset).sqrt();
+        if residual.norm() > tolerance::<V>() * scale {
+            return None;
+        }
+
+        let u = self.direction.basis.iter().zip(coefficients.iter()).fold(
+            OVector::zeros(),
+            |raw: OVector<V::Field, V::Dim>, (e, a)| raw + e._get_raw() * *a,
+        );
+        let point = &self.point + &V::_from_raw(u);
+        Some(Self::new(
+            point,
+            self.direction.intersection(&other.direction),
+        ))
+    }
+
+    pub fn angle(&self, other: &Self) -> V::Field {
+        self.direction.angle(&other.direction)
+    }
+
+    pub fn act<G: GroupAction<V> + GroupAction<AffineSpace<V>>>(&self, g: &G) -> Self {
+        Self::new(
+            GroupAction::<AffineSpace<V>>::act_on(g, &self.point),
+            self.direction.act(g),
+        )
+    }
+}
+
+fn tolerance<V: InnerProductSpace>() -> V::Field
+where
+    DefaultAllocator: Allocator<V::Dim>,
+{
+    V::Field::default_epsilon().sqrt()
+}
+
+// Unit vector along the part of `vector` orthogonal to the orthonormal `basis`, or None if it
+// lies in their span
+fn orthonormalize<V: InnerProductSpace>(basis: &[V], vector: &V) -> Option<V>
+where
+    DefaultAllocator: Allocator<V::Dim>,
+{
+    let raw = basis.iter().fold(vector._get_raw().clone(), |raw, e| {
+        raw - e._get_raw() * e.dot(vector)
+    });
+    let e = V::_from_raw(raw);
+    let norm = e.dot(&e).sqrt();
+    if norm <= tolerance::<V>() * vector.dot(vector).sqrt() || norm == V::Field::zero() {
+        return None;
+    }
+    Some(e.scale(V::Field::one() / norm))
+}
@@ -1,48 +1,394 @@
-use nalgebra as na;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
 
-use super::space::LinearSpace;
+use generic_array::ArrayLength;
+use nalgebra::{DimName, OMatrix, OVector, ToTypenum};
 
-pub trait Tensor<const N: usize, const R: usize, const S: usize> {
-    type V: LinearSpace<N>;
+use crate::error::{Error, Result};
+use crate::linear::basis::LinearChart;
+use crate::linear::space::{
+    Allocator, DefaultAllocator, InnerProductSpace, LinearSpace, MetricSpace,
+};
+use crate::manifold::{Manifold, Zero};
+
+// Tensor of type (R, S) over V: R contravariant (vector) slots followed by S covariant (covector)
+// slots. Components are stored in the reference basis of V, in row-major order with the
+// contravariant indices first, and are only exposed through a linear chart.
+
+#[derive(Clone, Debug)]
+pub struct Tensor<V: LinearSpace, const R: usize, const S: usize>
+where
+    DefaultAllocator: Allocator<V::Dim>,
+{
+    raw: Vec<V::Field>,
+    _space: PhantomData<V>,
+}
+
+impl<V: LinearSpace, const R: usize, const S: usize> Tensor<V, R, S>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+    V::Dim: DimName,
+{
+    pub fn zero() -> Self {
+        Self::_from_raw(vec![V::Field::zero(); len(R + S, dim::<V>())])
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        Self::_from_raw(
+            self.raw
+                .iter()
+                .zip(&other.raw)
+                .map(|(a, b)| *a + *b)
+                .collect(),
+        )
+    }
+
+    pub fn sub(&self, other: &Self) -> Self {
+        Self::_from_raw(
+            self.raw
+                .iter()
+                .zip(&other.raw)
+                .map(|(a, b)| *a - *b)
+                .collect(),
+        )
+    }
+
+    pub fn scale(&self, scalar: V::Field) -> Self {
+        Self::_from_raw(self.raw.iter().map(|a| *a * scalar).collect())
+    }
+
+    // Tensor product, with the contravariant slots of `self` followed by those of `other`, and
+    // likewise for the covariant slots
+    pub fn tensor<const R2: usize, const S2: usize, const R3: usize, const S3: usize>(
+        &self,
+        other: &Tensor<V, R2, S2>,
+    ) -> Tensor<V, R3, S3> {
+        const { assert!(R3 == R + R2 && S3 == S + S2) };
+        Tensor::_from_raw(outer(&self.raw, R, S, &other.raw, R2, S2, dim::<V>()))
+    }
+
+    // Contraction of the `upper`-th contravariant slot with the `lower`-th covariant slot
+    pub fn contract<const R2: usize, const S2: usize>(
+        &self,
+        upper: usize,
+        lower: usize,
+    ) -> Tensor<V, R2, S2> {
+        const { assert!(R2 + 1 == R && S2 + 1 == S) };
+        assert!(upper < R && lower < S, "Slot index out of range");
+        Tensor::_from_raw(contract(&self.raw, R + S, upper, R + lower, dim::<V>()))
+    }
+
+    // Full evaluation on R covectors and S vectors
+    pub fn evaluate(&self, covectors: [&V::DualSpace; R], vectors: [&V; S]) -> V::Field {
+        let raws: Vec<&OVector<V::Field, V::Dim>> = covectors
+            .iter()
+            .map(|c| c._get_raw())
+            .chain(vectors.iter().map(|v| v._get_raw()))
+            .collect();
+        let n = dim::<V>();
+        (0..self.raw.len())
+            .map(|flat| {
+                digits(flat, R + S, n)
+                    .iter()
+                    .zip(&raws)
+                    .fold(self.raw[flat], |acc, (i, raw)| acc * raw[*i])
+            })
+            .fold(V::Field::zero(), |acc, x| acc + x)
+    }
+
+    // Components in a basis, in the same slot order as the tensor
+    pub fn to_local<C: LinearChart<V>>(&self, basis: &C) -> Vec<V::Field>
+    where
+        V::Dim: ToTypenum<Typenum: ArrayLength>,
+    {
+        let (to_local, from_local) = (basis._to_local_matrix(), basis._from_local_matrix());
+        let covariant = from_local.transpose();
+        self.transform(&to_local, &covariant).raw
+    }
+
+    pub fn from_local<C: LinearChart<V>>(basis: &C, components: &[V::Field]) -> Self
+    where
+        V::Dim: ToTypenum<Typenum: ArrayLength>,
+    {
+        assert_eq!(
+            components.len(),
+            len(R + S, dim::<V>()),
+            "Wrong number of components"
+        );
+        let (to_local, from_local) = (basis._to_local_matrix(), basis._from_local_matrix());
+        let covariant = to_local.transpose();
+        Self::_from_raw(components.to_vec()).transform(&from_local, &covariant)
+    }
+
+    fn transform(&self, contravariant: &Matrix<V>, covariant: &Matrix<V>) -> Self {
+        let n = dim::<V>();
+        Self::_from_raw((0..R + S).fold(self.raw.clone(), |raw, slot| {
+            let matrix = if slot < R { contravariant } else { covariant };
+            transform_slot(&raw, R + S, slot, matrix, n)
+        }))
+    }
+
+    pub fn _get_raw(&self) -> &[V::Field] {
+        &self.raw
+    }
+    pub fn _from_raw(raw: Vec<V::Field>) -> Self {
+        Self {
+            raw,
+            _space: PhantomData,
+        }
+    }
 }
 
-pub struct Tensor1_1<const N: usize, V: LinearSpace<N>> {
-    pub raw: na::SMatrix<V::Field, N, N>,
+impl<V: LinearSpace> Tensor<V, 0, 0>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+    V::Dim: DimName,
+{
+    pub fn from_scalar(scalar: V::Field) -> Self {
+        Self::_from_raw(vec![scalar])
+    }
+
+    pub fn to_scalar(&self) -> V::Field {
+        self.raw[0]
+    }
 }
 
-impl<const N: usize, V: LinearSpace<N>> Tensor1_1<N, V> {
-    // fn apply(&self, vector: &V) -> V::DualSpace {
-    //     V::DualSpace {
-    //         raw: self.raw * vector.raw,
-    //     }
-    // }
+impl<V: LinearSpace> Tensor<V, 1, 0>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+    V::Dim: DimName,
+{
+    pub fn from_vector(vector: &V) -> Self {
+        Self::_from_raw(vector._get_raw().as_slice().to_vec())
+    }
+
+    pub fn to_vector(&self) -> V {
+        V::_from_raw(OVector::from_column_slice(&self.raw))
+    }
 }
 
-impl<const N: usize, V: LinearSpace<N>> Tensor<N, 1, 1> for Tensor1_1<N, V> {
-    type V = V;
+impl<V: LinearSpace> Tensor<V, 0, 1>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+    V::Dim: DimName,
+{
+    pub fn from_covector(covector: &V::DualSpace) -> Self {
+        Self::_from_raw(covector._get_raw().as_slice().to_vec())
+    }
+
+    pub fn to_covector(&self) -> V::DualSpace {
+        V::DualSpace::_from_raw(OVector::from_column_slice(&self.raw))
+    }
 }
 
-// struct Tensor2<const N: usize, K: Scalar> {
-//     raw: na::SMatrix<K, N, N>,
-// }
+// Linear operators on V
+
+impl<V: LinearSpace> Tensor<V, 1, 1>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+    V::Dim: DimName,
+{
+    pub fn identity() -> Self {
+        Self::_from_raw(Matrix::<V>::identity().transpose().as_slice().to_vec())
+    }
 
-// impl<const N: usize, K: Scalar> Tensor2<N, K> {
-//     fn from_columns(columns: [&Vector<N, K>; N]) -> Self {
-//         let raw = na::SMatrix::<K, N, N>::from_columns(&columns.map(|v| v.raw));
-//         Tensor2 {
-//             raw,
-//         }
-//     }
+    pub fn apply(&self, vector: &V) -> V {
+        V::_from_raw(self.matrix() * vector._get_raw())
+    }
 
-//     fn inverse(&self) -> Self {
-//         Tensor2 {
-//             raw: self.raw.try_inverse().expect("Matrix is not invertible"),
-//         }
-//     }
-// }
+    pub fn apply_dual(&self, covector: &V::DualSpace) -> V::DualSpace {
+        V::DualSpace::_from_raw(self.matrix().tr_mul(covector._get_raw()))
+    }
 
-// impl<const N: usize, V: LinearSpace<N>> Tensor<N, 2, 0> for Tensor2<N, V> {
-//     type V = V;
-// }
+    pub fn trace(&self) -> V::Field {
+        self.contract::<0, 0>(0, 0).to_scalar()
+    }
 
-// Basis for Linear Space
+    fn matrix(&self) -> Matrix<V> {
+        Matrix::<V>::from_row_slice(&self.raw)
+    }
+}
+
+// Metric tensors and the musical isomorphisms on tensors
+
+impl<V: MetricSpace> Tensor<V, 0, 2>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+    V::Dim: DimName,
+{
+    pub fn metric() -> Self {
+        let gram = V::_gram_matrix();
+        Self::_from_raw(gram.transpose().as_slice().to_vec())
+    }
+}
+
+impl<V: MetricSpace> Tensor<V, 2, 0>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+    V::Dim: DimName,
+{
+    // Inverse metric, which only exists for a nondegenerate metric
+    pub fn try_inverse_metric() -> Result<Self> {
+        let inverse = V::_gram_matrix().try_inverse().ok_or(Error::Singular)?;
+        Ok(Self::_from_raw(inverse.transpose().as_slice().to_vec()))
+    }
+}
+
+impl<V: InnerProductSpace> Tensor<V, 2, 0>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+    V::Dim: DimName,
+{
+    pub fn inverse_metric() -> Self {
+        Self::try_inverse_metric().expect("Inner products are nondegenerate")
+    }
+}
+
+impl<V: MetricSpace, const R: usize, const S: usize> Tensor<V, R, S>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+    V::Dim: DimName,
+{
+    // Lowers the `upper`-th contravariant slot, which becomes the last covariant slot
+    pub fn lower<const R2: usize, const S2: usize>(&self, upper: usize) -> Tensor<V, R2, S2> {
+        const { assert!(R2 + 1 == R && S2 == S + 1) };
+        assert!(upper < R, "Slot index out of range");
+        let n = dim::<V>();
+        let product = outer(
+            &self.raw,
+            R,
+            S,
+            Tensor::<V, 0, 2>::metric()._get_raw(),
+            0,
+            2,
+            n,
+        );
+        Tensor::_from_raw(contract(&product, R + S + 2, upper, R + S, n))
+    }
+
+    // Raises the `lower`-th covariant slot, which becomes the last contravariant slot, failing for a
+    // degenerate metric
+    pub fn try_raise<const R2: usize, const S2: usize>(
+        &self,
+        lower: usize,
+    ) -> Result<Tensor<V, R2, S2>> {
+        const { assert!(R2 == R + 1 && S2 + 1 == S) };
+        assert!(lower < S, "Slot index out of range");
+        let n = dim::<V>();
+        let inverse = Tensor::<V, 2, 0>::try_inverse_metric()?;
+        let product = outer(&self.raw, R, S, inverse._get_raw(), 2, 0, n);
+        Ok(Tensor::_from_raw(contract(
+            &product,
+            R + S + 2,
+            R,
+            R + 2 + lower,
+            n,
+        )))
+    }
+}
+
+impl<V: InnerProductSpace, const R: usize, const S: usize> Tensor<V, R, S>
+where
+    DefaultAllocator: Allocator<V::Dim> + Allocator<V::Dim, V::Dim>,
+    V::Dim: DimName,
+{
+    pub fn raise<const R2: usize, const S2: usize>(&self, lower: usize) -> Tensor<V, R2, S2> {
+        self.try_raise(lower)
+            .expect("Inner products are nondegenerate")
+    }
+}
+
+type Matrix<V> = OMatrix<<V as Manifold>::Field, <V as Manifold>::Dim, <V as Manifold>::Dim>;
+
+fn dim<V: Manifold>() -> usize
+where
+    V::Dim: DimName,
+{
+    V::Dim::dim()
+}
+
+fn len(rank: usize, n: usize) -> usize {
+    n.pow(rank as u32)
+}
+
+fn digits(mut flat: usize, rank: usize, n: usize) -> Vec<usize> {
+    let mut digits = vec![0; rank];
+    for digit in digits.iter_mut().rev() {
+        *digit = flat % n;
+        flat /= n;
+    }
+    digits
+}
+
+fn flatten(digits: &[usize], n: usize) -> usize {
+    digits.iter().fold(0, |acc, d| acc * n + d)
+}
+
+// Component-level operations, with ranks known only at runtime
+
+fn outer<K: Copy + core::ops::Mul<Output = K>>(
+    a: &[K],
+    ra: usize,
+    sa: usize,
+    b: &[K],
+    rb: usize,
+    sb: usize,
+    n: usize,
+) -> Vec<K> {
+    let rank = ra + sa + rb + sb;
+    (0..len(rank, n))
+        .map(|flat| {
+            let d = digits(flat, rank, n);
+            let (upper_a, rest) = d.split_at(ra);
+            let (upper_b, rest) = rest.split_at(rb);
+            let (lower_a, lower_b) = rest.split_at(sa);
+            let ia = flatten(&[upper_a, lower_a].concat(), n);
+            let ib = flatten(&[upper_b, lower_b].concat(), n);
+            a[ia] * b[ib]
+        })
+        .collect()
+}
+
+fn contract<K: Copy + Zero>(
+    raw: &[K],
+    rank: usize,
+    first: usize,
+    second: usize,
+    n: usize,
+) -> Vec<K> {
+    (0..len(rank - 2, n))
+        .map(|flat| {
+            let mut d = digits(flat, rank - 2, n);
+            d.insert(first, 0);
+            d.insert(second, 0);
+            (0..n).fold(K::zero(), |acc, k| {
+                d[first] = k;
+                d[second] = k;
+                acc + raw[flatten(&d, n)]
+            })
+        })
+        .collect()
+}
+
+fn transform_slot<K: nalgebra::Scalar + Copy + Zero + core::ops::Mul<Output = K>, N: DimName>(
+    raw: &[K],
+    rank: usize,
+    slot: usize,
+    matrix: &OMatrix<K, N, N>,
+    n: usize,
+) -> Vec<K>
+where
+    DefaultAllocator: Allocator<N, N>,
+{
+    (0..raw.len())
+        .map(|flat| {
+            let mut d = digits(flat, rank, n);
+            let a = d[slot];
+            (0..n).fold(K::zero(), |acc, i| {
+                d[slot] = i;
+                acc + matrix[(a, i)] * raw[flatten(&d, n)]
+            })
+        })
+        .collect()
+}
@@ -1,22 +1,35 @@
+pub use core::ops::{Add, Neg};
 use generic_array::{ArrayLength, GenericArray};
-use nalgebra::{DimName, RealField};
+use nalgebra::{Dim, RealField, ToTypenum};
 pub use num_traits::{Num, One, Zero};
-pub use std::ops::{Add, Neg};
 
 pub trait Scalar: RealField + Copy + Num + Zero + One + Neg {}
 impl<T: RealField + Copy + Num + Zero + One + Neg> Scalar for T {}
+
+// Total order on scalars for sorting, placing NaN after every number as `f64::total_cmp` does
+pub(crate) fn total_cmp<K: Scalar>(a: &K, b: &K) -> core::cmp::Ordering {
+    let is_nan = |x: &K| x.partial_cmp(x).is_none();
+    a.partial_cmp(b)
+        .unwrap_or_else(|| is_nan(a).cmp(&is_nan(b)))
+}
+
 pub trait Manifold: Sized {
     type Field: Scalar;
-    type Dim: DimName;
+    type Dim: Dim;
 }
 
+// Components of a point in a chart, e.g. `GenericArray<f64, typenum::U3>` for a 3D manifold
+pub type Components<M> =
+    GenericArray<<M as Manifold>::Field, <<M as Manifold>::Dim as ToTypenum>::Typenum>;
+
+#[allow(clippy::wrong_self_convention)]
 pub trait Chart<M: Manifold> {
     // type InducedVectorField: VectorField<N, M = M>;
 
-    fn to_local(&self, point: &M) -> GenericArray<M::Field, M::Dim>
+    fn to_local(&self, point: &M) -> Components<M>
     where
-        M::Dim: ArrayLength;
-    fn from_local(&self, components: &GenericArray<M::Field, M::Dim>) -> M
+        M::Dim: ToTypenum<Typenum: ArrayLength>;
+    fn from_local(&self, components: &Components<M>) -> M
     where
-        M::Dim: ArrayLength;
+        M::Dim: ToTypenum<Typenum: ArrayLength>;
 }
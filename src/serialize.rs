@@ -0,0 +1,444 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+use generic_array::{ArrayLength, GenericArray};
+use nalgebra::{Dim, DimMin, DimName, OMatrix, OVector, ToTypenum, U1, U3, U6, Vector3};
+use serde::de::{self, DeserializeSeed, Deserializer, SeqAccess, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::euclidean::{Covector, EuclideanSpace, Motion, Motion3D, Rotation, Vector};
+use crate::kinematics::Kinematics;
+use crate::lie::{LieAlgebra, Torsor};
+use crate::linear::basis::{LinearChart, OrthonormalLinearBasis};
+use crate::linear::group::euclidean::SERepr;
+use crate::linear::group::orthogonal::SORepr;
+use crate::linear::space::{Allocator, DefaultAllocator, LinearSpace};
+use crate::linear::{SpecialEuclideanGroup, SpecialOrthogonalGroup};
+use crate::manifold::{Chart, Scalar};
+
+// Components only have a meaning in a frame, so values are never serialized on their own: the
+// frame is given alongside the value when serializing, and again when deserializing. The frame
+// itself is not written, so it is up to the caller to persist which frame was used.
+
+pub trait SerializeInFrame<C> {
+    fn serialize_in_frame<S: Serializer>(
+        &self,
+        frame: &C,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>;
+}
+
+pub trait DeserializeInFrame<'de, C>: Sized {
+    fn deserialize_in_frame<D: Deserializer<'de>>(
+        frame: &C,
+        deserializer: D,
+    ) -> Result<Self, D::Error>;
+}
+
+// A value together with the frame its components are taken in, e.g.
+// `serde_json::to_string(&WithFrame::new(&basis, &vector))`
+
+pub struct WithFrame<'a, C, T> {
+    pub frame: &'a C,
+    pub value: &'a T,
+}
+
+impl<'a, C, T> WithFrame<'a, C, T> {
+    pub fn new(frame: &'a C, value: &'a T) -> Self {
+        Self { frame, value }
+    }
+}
+
+impl<C, T: SerializeInFrame<C>> Serialize for WithFrame<'_, C, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize_in_frame(self.frame, serializer)
+    }
+}
+
+// Seed reading a value whose components are taken in the frame, e.g.
+// `InFrame::<_, Vector<U3>>::new(&basis).deserialize(&mut deserializer)`
+
+pub struct InFrame<'a, C, T> {
+    pub frame: &'a C,
+    _value: PhantomData<T>,
+}
+
+impl<'a, C, T> InFrame<'a, C, T> {
+    pub fn new(frame: &'a C) -> Self {
+        Self {
+            frame,
+            _value: PhantomData,
+        }
+    }
+}
+
+impl<'de, C, T: DeserializeInFrame<'de, C>> DeserializeSeed<'de> for InFrame<'_, C, T> {
+    type Value = T;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_in_frame(self.frame, deserializer)
+    }
+}
+
+// Vectors, covectors and points, as their components in a chart
+
+impl<N: DimName, K: Scalar + Serialize, C: Chart<Vector<N, K>>> SerializeInFrame<C> for Vector<N, K>
+where
+    N: ToTypenum<Typenum: ArrayLength>,
+    DefaultAllocator: Allocator<N>,
+{
+    fn serialize_in_frame<S: Serializer>(
+        &self,
+        frame: &C,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(frame.to_local(self).iter())
+    }
+}
+
+impl<'de, N: DimName, K: Scalar + Deserialize<'de>, C: Chart<Vector<N, K>>>
+    DeserializeInFrame<'de, C> for Vector<N, K>
+where
+    N: ToTypenum<Typenum: ArrayLength>,
+    DefaultAllocator: Allocator<N>,
+{
+    fn deserialize_in_frame<D: Deserializer<'de>>(
+        frame: &C,
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let RowMajor(components) = RowMajor::<K, N, U1>::deserialize(deserializer)?;
+        Ok(frame.from_local(GenericArray::from_slice(components.as_slice())))
+    }
+}
+
+impl<N: DimName, K: Scalar + Serialize, C: Chart<EuclideanSpace<N, K>>> SerializeInFrame<C>
+    for EuclideanSpace<N, K>
+where
+    N: ToTypenum<Typenum: ArrayLength>,
+    DefaultAllocator: Allocator<N>,
+{
+    fn serialize_in_frame<S: Serializer>(
+        &self,
+        frame: &C,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(frame.to_local(self).iter())
+    }
+}
+
+impl<'de, N: DimName, K: Scalar + Deserialize<'de>, C: Chart<EuclideanSpace<N, K>>>
+    DeserializeInFrame<'de, C> for EuclideanSpace<N, K>
+where
+    N: ToTypenum<Typenum: ArrayLength>,
+    DefaultAllocator: Allocator<N>,
+{
+    fn deserialize_in_frame<D: Deserializer<'de>>(
+        frame: &C,
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let RowMajor(components) = RowMajor::<K, N, U1>::deserialize(deserializer)?;
+        Ok(frame.from_local(GenericArray::from_slice(components.as_slice())))
+    }
+}
+
+// Covectors take the components dual to those of vectors, α_i = α(e_i)
+
+impl<N: DimName, K: Scalar + Serialize, C: LinearChart<Vector<N, K>>> SerializeInFrame<C>
+    for Covector<N, K>
+where
+    N: ToTypenum<Typenum: ArrayLength>,
+    DefaultAllocator: Allocator<N> + Allocator<N, N>,
+{
+    fn serialize_in_frame<S: Serializer>(
+        &self,
+        frame: &C,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        RowMajor(frame._from_local_matrix().tr_mul(self._get_raw())).serialize(serializer)
+    }
+}
+
+impl<'de, N: DimName, K: Scalar + Deserialize<'de>, C: LinearChart<Vector<N, K>>>
+    DeserializeInFrame<'de, C> for Covector<N, K>
+where
+    N: ToTypenum<Typenum: ArrayLength>,
+    DefaultAllocator: Allocator<N> + Allocator<N, N>,
+{
+    fn deserialize_in_frame<D: Deserializer<'de>>(
+        frame: &C,
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let RowMajor(components) = RowMajor::<K, N, U1>::deserialize(deserializer)?;
+        Ok(Self::_from_raw(
+            frame._to_local_matrix().tr_mul(&components),
+        ))
+    }
+}
+
+// Rotations, as their matrix in a linear chart in row-major order
+
+impl<N: DimName, K: Scalar + Serialize, C: LinearChart<Vector<N, K>>> SerializeInFrame<C>
+    for Rotation<N, K>
+where
+    N: SORepr<Vector<N, K>> + ToTypenum<Typenum: ArrayLength>,
+    DefaultAllocator: Allocator<N> + Allocator<N, N>,
+{
+    fn serialize_in_frame<S: Serializer>(
+        &self,
+        frame: &C,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let matrix = matrix_of(|v| N::act(&self.repr, v));
+        RowMajor(frame._to_local_matrix() * matrix * frame._from_local_matrix())
+            .serialize(serializer)
+    }
+}
+
+impl<'de, N: DimName, K: Scalar + Deserialize<'de>, C: LinearChart<Vector<N, K>>>
+    DeserializeInFrame<'de, C> for Rotation<N, K>
+where
+    N: SORepr<Vector<N, K>> + DimMin<N, Output = N> + ToTypenum<Typenum: ArrayLength>,
+    DefaultAllocator: Allocator<N> + Allocator<N, N>,
+{
+    fn deserialize_in_frame<D: Deserializer<'de>>(
+        frame: &C,
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let RowMajor(local) = RowMajor::<K, N, N>::deserialize(deserializer)?;
+        let matrix = rotation_from_local(frame, local).map_err(de::Error::custom)?;
+        Ok(SpecialOrthogonalGroup {
+            repr: N::from_matrix(&matrix),
+        })
+    }
+}
+
+// Motions x ↦ R x + t, as the rotation matrix and the translation in a linear chart, that is a
+// frame whose origin is the reference origin
+
+#[derive(Serialize, Deserialize)]
+struct MotionComponents<R, T> {
+    rotation: R,
+    translation: T,
+}
+
+impl<N: DimName, K: Scalar + Serialize, C: LinearChart<Vector<N, K>>> SerializeInFrame<C>
+    for Motion<N, K>
+where
+    N: SERepr<Vector<N, K>> + ToTypenum<Typenum: ArrayLength>,
+    DefaultAllocator: Allocator<N> + Allocator<N, N>,
+{
+    fn serialize_in_frame<S: Serializer>(
+        &self,
+        frame: &C,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        motion_to_local(frame, self).serialize(serializer)
+    }
+}
+
+impl<'de, N: DimName, K: Scalar + Deserialize<'de>, C: LinearChart<Vector<N, K>>>
+    DeserializeInFrame<'de, C> for Motion<N, K>
+where
+    N: SERepr<Vector<N, K>> + DimMin<N, Output = N> + ToTypenum<Typenum: ArrayLength>,
+    DefaultAllocator: Allocator<N> + Allocator<N, N>,
+{
+    fn deserialize_in_frame<D: Deserializer<'de>>(
+        frame: &C,
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let components = MotionComponents::deserialize(deserializer)?;
+        motion_from_local(frame, components).map_err(de::Error::custom)
+    }
+}
+
+// Rigid body states, as the pose and the angular and linear velocities in an orthonormal basis,
+// and the accelerations when they are not zero
+
+#[derive(Serialize, Deserialize)]
+struct KinematicsComponents<P, W> {
+    pose: P,
+    angular_velocity: W,
+    linear_velocity: W,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    angular_acceleration: Option<W>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    linear_acceleration: Option<W>,
+}
+
+impl<K: Scalar + Serialize> SerializeInFrame<OrthonormalLinearBasis<Vector<U3, K>>>
+    for Kinematics<Motion3D<K>>
+{
+    fn serialize_in_frame<S: Serializer>(
+        &self,
+        frame: &OrthonormalLinearBasis<Vector<U3, K>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let to_local = frame._to_local_matrix();
+        let velocity = self.velocity._get_raw();
+        let acceleration = self.acceleration._get_raw();
+        let accelerating = acceleration.iter().any(|x| !x.is_zero());
+        let local = |part| accelerating.then(|| RowMajor(to_local * part));
+        KinematicsComponents {
+            pose: motion_to_local(frame, &self.point._from_origin),
+            angular_velocity: RowMajor(to_local * velocity.fixed_rows::<3>(0)),
+            linear_velocity: RowMajor(to_local * velocity.fixed_rows::<3>(3)),
+            angular_acceleration: local(acceleration.fixed_rows::<3>(0)),
+            linear_acceleration: local(acceleration.fixed_rows::<3>(3)),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, K: Scalar + Deserialize<'de>>
+    DeserializeInFrame<'de, OrthonormalLinearBasis<Vector<U3, K>>> for Kinematics<Motion3D<K>>
+{
+    fn deserialize_in_frame<D: Deserializer<'de>>(
+        frame: &OrthonormalLinearBasis<Vector<U3, K>>,
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let components: KinematicsComponents<_, RowMajor<K, U3, U1>> =
+            KinematicsComponents::deserialize(deserializer)?;
+        let pose = motion_from_local(frame, components.pose).map_err(de::Error::custom)?;
+        let from_local = frame._from_local_matrix();
+        let join = |angular: Option<RowMajor<K, U3, U1>>, linear: Option<RowMajor<K, U3, U1>>| {
+            let (angular, linear) = (
+                from_local * angular.map_or_else(Vector3::zeros, |w| w.0),
+                from_local * linear.map_or_else(Vector3::zeros, |v| v.0),
+            );
+            let raw =
+                OVector::<K, U6>::from_fn(|i, _| if i < 3 { angular[i] } else { linear[i - 3] });
+            LieAlgebra::_from_raw(raw)
+        };
+        Ok(Kinematics::new(
+            Torsor { _from_origin: pose },
+            join(
+                Some(components.angular_velocity),
+                Some(components.linear_velocity),
+            ),
+        )
+        .with_acceleration(join(
+            components.angular_acceleration,
+            components.linear_acceleration,
+        )))
+    }
+}
+
+// Helpers between values and their local components
+
+fn matrix_of<N: DimName, K: Scalar>(map: impl Fn(&Vector<N, K>) -> Vector<N, K>) -> OMatrix<K, N, N>
+where
+    DefaultAllocator: Allocator<N> + Allocator<N, N>,
+{
+    OMatrix::<K, N, N>::from_fn(|i, j| {
+        let unit = Vector::_from_raw(OVector::from_fn(
+            |k, _| if k == j { K::one() } else { K::zero() },
+        ));
+        map(&unit)._get_raw()[i]
+    })
+}
+
+// Matrix in reference components of the rotation with the given matrix in the chart
+fn rotation_from_local<N, K: Scalar, C: LinearChart<Vector<N, K>>>(
+    frame: &C,
+    local: OMatrix<K, N, N>,
+) -> Result<OMatrix<K, N, N>, &'static str>
+where
+    N: DimName + DimMin<N, Output = N> + ToTypenum<Typenum: ArrayLength>,
+    DefaultAllocator: Allocator<N> + Allocator<N, N>,
+{
+    let matrix = frame._from_local_matrix() * local * frame._to_local_matrix();
+    let tolerance = K::default_epsilon().sqrt();
+    let orthogonal = (matrix.tr_mul(&matrix) - OMatrix::<K, N, N>::identity()).norm() <= tolerance;
+    if !orthogonal || matrix.determinant() <= K::zero() {
+        return Err("Matrix is not a rotation");
+    }
+    Ok(matrix)
+}
+
+type LocalMotion<K, N> = MotionComponents<RowMajor<K, N, N>, RowMajor<K, N, U1>>;
+
+fn motion_to_local<N, K: Scalar, C: LinearChart<Vector<N, K>>>(
+    frame: &C,
+    motion: &Motion<N, K>,
+) -> LocalMotion<K, N>
+where
+    N: DimName + SERepr<Vector<N, K>> + ToTypenum<Typenum: ArrayLength>,
+    DefaultAllocator: Allocator<N> + Allocator<N, N>,
+{
+    let to_local = frame._to_local_matrix();
+    let rotation = matrix_of(|v| N::act_on_vector(&motion.repr, v));
+    let translation = N::act_on_point(&motion.repr, &Vector::zero());
+    MotionComponents {
+        rotation: RowMajor(&to_local * rotation * frame._from_local_matrix()),
+        translation: RowMajor(to_local * translation._get_raw()),
+    }
+}
+
+fn motion_from_local<N, K: Scalar, C: LinearChart<Vector<N, K>>>(
+    frame: &C,
+    components: LocalMotion<K, N>,
+) -> Result<Motion<N, K>, &'static str>
+where
+    N: DimName + SERepr<Vector<N, K>> + DimMin<N, Output = N> + ToTypenum<Typenum: ArrayLength>,
+    DefaultAllocator: Allocator<N> + Allocator<N, N>,
+{
+    let rotation = rotation_from_local(frame, components.rotation.0)?;
+    let translation = Vector::_from_raw(frame._from_local_matrix() * components.translation.0);
+    Ok(SpecialEuclideanGroup {
+        repr: N::from_parts(&rotation, &translation),
+    })
+}
+
+// Matrix written as a flat sequence in row-major order, which needs no allocation to read back
+
+struct RowMajor<K: Scalar, R: Dim, C: Dim>(OMatrix<K, R, C>)
+where
+    DefaultAllocator: Allocator<R, C>;
+
+impl<K: Scalar + Serialize, R: Dim, C: Dim> Serialize for RowMajor<K, R, C>
+where
+    DefaultAllocator: Allocator<R, C>,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (rows, columns) = self.0.shape();
+        serializer.collect_seq((0..rows).flat_map(|i| (0..columns).map(move |j| self.0[(i, j)])))
+    }
+}
+
+impl<'de, K: Scalar + Deserialize<'de>, R: DimName, C: DimName> Deserialize<'de>
+    for RowMajor<K, R, C>
+where
+    DefaultAllocator: Allocator<R, C>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(RowMajorVisitor(PhantomData))
+    }
+}
+
+struct RowMajorVisitor<K, R, C>(PhantomData<(K, R, C)>);
+
+impl<'de, K: Scalar + Deserialize<'de>, R: DimName, C: DimName> Visitor<'de>
+    for RowMajorVisitor<K, R, C>
+where
+    DefaultAllocator: Allocator<R, C>,
+{
+    type Value = RowMajor<K, R, C>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a sequence of {} components", R::dim() * C::dim())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let len = R::dim() * C::dim();
+        let mut matrix = OMatrix::<K, R, C>::zeros();
+        for n in 0..len {
+            matrix[(n / C::dim(), n % C::dim())] = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(n, &self))?;
+        }
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(len + 1, &self));
+        }
+        Ok(RowMajor(matrix))
+    }
+}
@@ -1,4 +1,136 @@
-use geometrica::astrodynamics;
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+use geometrica::astrodynamics::*;
+use geometrica::euclidean::*;
+use geometrica::kinematics::Kinematics;
+use geometrica::linear::basis::OrthonormalLinearBasis;
+
+mod common;
+
+use common::assert_within;
 
 #[test]
 fn test_triad() {}
+
+#[test]
+fn test_ellipsoid_constants() {
+    let wgs84 = Ellipsoid::<f64>::wgs84();
+    assert_within(&[wgs84.semi_minor_axis()], &[6_356_752.314_245], 1e-6);
+    assert_within(
+        &[wgs84.eccentricity_squared()],
+        &[6.694_379_990_14e-3],
+        1e-14,
+    );
+
+    let grs80 = Ellipsoid::<f64>::grs80();
+    assert_within(&[grs80.semi_minor_axis()], &[6_356_752.314_140], 1e-6);
+
+    let sphere = Ellipsoid::from_axes(1.0, 1.0);
+    assert_eq!(sphere.eccentricity_squared(), 0.0);
+    assert_within(&[sphere.geocentric_latitude(0.3)], &[0.3], 1e-15);
+}
+
+#[test]
+fn test_geodetic_conversion() {
+    let wgs84 = Ellipsoid::wgs84();
+    let a = wgs84.semi_major_axis();
+    let b = wgs84.semi_minor_axis();
+
+    let equator = wgs84.to_cartesian(&[0.0, 0.0, 0.0].into());
+    assert_within(&equator, &[a, 0.0, 0.0], 1e-9);
+
+    let pole = wgs84.to_cartesian(&[FRAC_PI_2, 0.0, 100.0].into());
+    assert_within(&pole, &[0.0, 0.0, b + 100.0], 1e-6);
+    assert_within(
+        &wgs84.to_geodetic(&pole)[..],
+        &[FRAC_PI_2, 0.0, 100.0],
+        1e-9,
+    );
+
+    let south_pole = wgs84.to_geodetic(&[0.0, 0.0, -b].into());
+    assert_within(&south_pole[..], &[-FRAC_PI_2, 0.0, 0.0], 1e-9);
+
+    for geodetic in [
+        [0.6108652381980153, 2.426007660272118, 40.0],
+        [-0.7, -1.2, -400.0],
+        [1.5, 0.1, 35_786_000.0],
+        [0.0001, 3.1, 8848.0],
+    ] {
+        let cartesian = wgs84.to_cartesian(&geodetic.into());
+        let back = wgs84.to_geodetic(&cartesian);
+        assert_within(&back[..2], &geodetic[..2], 1e-12);
+        assert_within(&back[2..], &geodetic[2..], 1e-6);
+    }
+}
+
+#[test]
+fn test_geocentric_latitude() {
+    let wgs84 = Ellipsoid::wgs84();
+    let ecef = Kinematics::stationary(ECI::as_reference());
+    let chart = GeodeticChart::new(wgs84, &ecef);
+
+    let point = chart.from_local(&[FRAC_PI_4, 0.5, 0.0].into());
+    let expected = wgs84.geocentric_latitude(FRAC_PI_4);
+    assert_within(&[chart.geocentric_latitude(&point)], &[expected], 1e-12);
+    assert_within(&[expected.to_degrees()], &[44.807_576_6], 1e-6);
+}
+
+#[test]
+fn test_geodetic_chart_round_trip() {
+    let ecef = Kinematics::stationary(ECI::as_reference());
+    let chart = GeodeticChart::wgs84(&ecef);
+
+    let point = chart.from_local(&[0.3, -2.0, 1200.0].into());
+    let geodetic = chart.to_local(&point);
+    assert_within(&geodetic[..2], &[0.3, -2.0], 1e-12);
+    assert_within(&geodetic[2..], &[1200.0], 1e-6);
+}
+
+#[test]
+fn test_local_frames() {
+    let ecef = Kinematics::stationary(ECI::as_reference());
+    let chart = GeodeticChart::wgs84(&ecef);
+    let reference = OrthonormalLinearBasis::<Vector<U3>> {
+        _from_origin: Rotation3D::identity(),
+    };
+    let x = reference.from_local(&[1.0, 0.0, 0.0].into());
+    let z = reference.from_local(&[0.0, 0.0, 1.0].into());
+
+    let origin = chart.from_local(&[0.0, 0.0, 0.0].into());
+    let enu = chart.enu_basis(&origin);
+    assert_within(&enu.to_local(&x), &[0.0, 0.0, 1.0], 1e-12);
+    assert_within(&enu.to_local(&z), &[0.0, 1.0, 0.0], 1e-12);
+
+    let ned = chart.ned_basis(&origin);
+    assert_within(&ned.to_local(&x), &[0.0, 0.0, -1.0], 1e-12);
+    assert_within(&ned.to_local(&z), &[1.0, 0.0, 0.0], 1e-12);
+
+    let north_pole = chart.from_local(&[FRAC_PI_2, 0.0, 0.0].into());
+    let enu = chart.enu_basis(&north_pole);
+    assert_within(&enu.to_local(&z), &[0.0, 0.0, 1.0], 1e-12);
+    assert_within(&enu.to_local(&x), &[0.0, -1.0, 0.0], 1e-12);
+}
+
+#[test]
+fn test_earth_rotation() {
+    // Points fixed in ECI drift westwards in ECEF at the rotation rate of the Earth
+    let hour = 3600.0;
+    let ecef = ECEF::at_time(hour);
+    let chart = GeodeticChart::wgs84(&ecef);
+    let inertial = GeodeticChart::wgs84(&ECEF::at_time(0.0)).from_local(&[0.0, 0.5, 0.0].into());
+    let geodetic = chart.to_local(&inertial);
+    assert_within(
+        &geodetic,
+        &[0.0, 0.5 - EARTH_ROTATION_RATE * hour, 0.0],
+        1e-6,
+    );
+
+    // and are carried eastwards with the ground at the equator
+    let frame = earth_rotating_frame(hour);
+    let speed = frame.velocity_in_reference(&inertial, &Vector::zero());
+    assert_within(
+        &[speed._get_raw().norm()],
+        &[EARTH_ROTATION_RATE * 6_378_137.0],
+        1e-6,
+    );
+}
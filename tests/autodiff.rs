@@ -0,0 +1,150 @@
+use geometrica::astrodynamics::Ellipsoid;
+use geometrica::autodiff::*;
+use geometrica::euclidean::*;
+use geometrica::lie::GroupAction;
+use geometrica::linear::basis::OrthonormalLinearBasis;
+use nalgebra::{ComplexField, Matrix1x2, Matrix2, RealField, Vector1, Vector2, Vector3};
+
+mod common;
+
+use common::assert_within;
+
+#[test]
+fn test_scalar_derivatives() {
+    assert_within(
+        &[derivative(|x| x.sin() * x, 0.7)],
+        &[0.7f64.cos() * 0.7 + 0.7f64.sin()],
+        1e-15,
+    );
+    assert_within(
+        &[derivative(|x| x.exp().ln().sqrt(), 2.0)],
+        &[0.5 / 2.0f64.sqrt()],
+        1e-15,
+    );
+    assert_within(
+        &[derivative(|x| x.atan2(Dual::constant(2.0)), 1.0)],
+        &[2.0 / 5.0],
+        1e-15,
+    );
+    assert_within(
+        &[derivative(|x| x.powf(x), 2.0)],
+        &[4.0 * (2.0f64.ln() + 1.0)],
+        1e-14,
+    );
+    assert_within(&[derivative(|x| x.powi(3), -2.0)], &[12.0], 1e-15);
+    assert_within(
+        &[derivative(|x| x.hypot(Dual::constant(4.0)), 3.0)],
+        &[0.6],
+        1e-15,
+    );
+
+    assert_within(
+        &[second_derivative(|x| x.sin(), 0.3)],
+        &[-0.3f64.sin()],
+        1e-15,
+    );
+    assert_within(&[second_derivative(|x| x * x * x, 1.5)], &[9.0], 1e-14);
+    assert_within(
+        &[second_derivative(|x| x.acos(), 0.5)],
+        &[-0.5 / 0.75f64.powf(1.5)],
+        1e-14,
+    );
+}
+
+#[test]
+fn test_jacobian_and_hessian() {
+    let x = Vector2::new(1.5, -0.5);
+
+    let j = jacobian(|v| Vector2::new(v[0] * v[1], v[0].sin() + v[1]), &x);
+    let expected = Matrix2::new(-0.5, 1.5, 1.5f64.cos(), 1.0);
+    assert_within(j.as_slice(), expected.as_slice(), 1e-15);
+
+    let h = hessian(|v| v[0] * v[0] * v[1] + v[1].exp(), &x);
+    let expected = Matrix2::new(-1.0, 3.0, 3.0, (-0.5f64).exp());
+    assert_within(h.as_slice(), expected.as_slice(), 1e-15);
+}
+
+#[test]
+fn test_square_root_at_zero() {
+    assert_eq!(derivative(|x| x.sqrt(), 0.0), f64::INFINITY);
+
+    // The squared norm is constant to first order at a zero vector, so its root gets a zero
+    // derivative rather than NaN
+    let norm = |v: &Vector2<Dual>| Vector1::new((v[0] * v[0] + v[1] * v[1]).sqrt());
+    assert_eq!(jacobian(norm, &Vector2::zeros()), Matrix1x2::zeros());
+}
+
+#[test]
+fn test_second_derivatives_of_square_root() {
+    // The first-order part vanishes at these points, but the mixed part must still be scaled
+    let h = hessian(
+        |v| (HyperDual::constant(Dual::constant(1.0)) + v[0] * v[1]).sqrt(),
+        &Vector2::new(0.0, 1.0),
+    );
+    let expected = Matrix2::new(-0.25, 0.5, 0.5, 0.0);
+    assert_within(h.as_slice(), expected.as_slice(), 1e-15);
+    assert_within(
+        &[second_derivative(
+            |t| (HyperDual::constant(Dual::constant(1.0)) + t * t).sqrt(),
+            0.0,
+        )],
+        &[1.0],
+        1e-15,
+    );
+}
+
+#[test]
+fn test_rotation_derivative() {
+    // d/dθ R(θ) v = ω × R(θ) v for a rotation about ω
+    let reference = OrthonormalLinearBasis::<Vector<U3, Dual>> {
+        _from_origin: Rotation3D::identity(),
+    };
+    let axis = reference.from_local(&[0.0, 0.0, 1.0].map(Dual::constant).into());
+    let v = reference.from_local(&[1.0, 2.0, 3.0].map(Dual::constant).into());
+
+    let theta = 0.4;
+    let rotation = Rotation3D::from_axis_angle(&axis, Dual::variable(theta));
+    let rotated = reference.to_local(&rotation.act_on(&v));
+
+    let (sin, cos) = theta.sin_cos();
+    let expected = Vector3::new(-sin - 2.0 * cos, cos - 2.0 * sin, 0.0);
+    for i in 0..3 {
+        assert_within(&[rotated[i].derivative()], &[expected[i]], 1e-15);
+    }
+    assert_within(&[rotated[0].value()], &[cos - 2.0 * sin], 1e-15);
+}
+
+#[test]
+fn test_geodetic_jacobian() {
+    // The derivative of the ECEF position with respect to height is the local up direction
+    let wgs84 = Ellipsoid::<Dual>::wgs84();
+    let (latitude, longitude) = (0.6, -1.1);
+    let j = jacobian(
+        |h: &Vector1<Dual>| {
+            let xyz = wgs84
+                .to_cartesian(&[Dual::constant(latitude), Dual::constant(longitude), h[0]].into());
+            Vector3::new(xyz[0], xyz[1], xyz[2])
+        },
+        &Vector1::new(100.0),
+    );
+    let up = Vector3::new(
+        latitude.cos() * longitude.cos(),
+        latitude.cos() * longitude.sin(),
+        latitude.sin(),
+    );
+    assert!((j.column(0) - up).norm() < 1e-15);
+
+    // Second derivatives through nested duals
+    assert_within(
+        &[second_derivative(
+            |x| x.sin().atan2(HyperDual::<f64>::pi() - x),
+            1.0,
+        )],
+        &[{
+            let f = |x: f64| x.sin().atan2(std::f64::consts::PI - x);
+            let h = 1e-4;
+            (f(1.0 + h) - 2.0 * f(1.0) + f(1.0 - h)) / (h * h)
+        }],
+        1e-6,
+    );
+}
@@ -0,0 +1,26 @@
+use std::path::Path;
+use std::process::Command;
+
+// Builds `ci/bare-metal`, a `no_std` static library without an allocator, against the crate with
+// default features off. Set `BARE_METAL_TARGET` (e.g. `thumbv7em-none-eabihf`) to cross-compile
+// for a microcontroller; otherwise the host target is used, which still rejects std and the heap.
+#[test]
+fn test_builds_without_std_or_heap() {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("ci/bare-metal/Cargo.toml");
+    let mut command = Command::new(env!("CARGO"));
+    command
+        .arg("build")
+        .arg("--manifest-path")
+        .arg(&manifest)
+        .arg("--target-dir")
+        .arg(Path::new(env!("CARGO_TARGET_TMPDIR")).join("bare-metal"));
+    if let Ok(target) = std::env::var("BARE_METAL_TARGET") {
+        command.arg("--target").arg(target);
+    }
+    let output = command.output().expect("Failed to run cargo");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
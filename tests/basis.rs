@@ -0,0 +1,213 @@
+use std::f64::consts::{FRAC_1_SQRT_2, FRAC_PI_2};
+
+use geometrica::Error;
+use geometrica::euclidean::*;
+use geometrica::lie::{GroupAction, LieGroup};
+use geometrica::linear::basis::{LinearBasis, OrthonormalLinearBasis};
+use geometrica::linear::{GeneralLinearGroup, LinearMap, Orientation};
+use nalgebra::Matrix3;
+
+mod common;
+
+use common::{V3, assert_close, vector};
+
+type V2 = Vector<U2>;
+
+#[test]
+fn test_lvlh_basis() {
+    // x along the velocity and z along the angular momentum, for an inclined circular orbit
+    let position = vector([7.0, 0.0, 0.0]);
+    let velocity = vector([0.0, 6.0, 4.0]);
+    let momentum = vector([0.0, -28.0, 42.0]);
+    let basis = OrthonormalLinearBasis::from_axes(&[(2, &momentum), (0, &velocity)]).unwrap();
+
+    let speed = velocity.dot(&velocity).sqrt();
+    assert_close(&basis.to_local(&velocity), &[speed, 0.0, 0.0]);
+    assert_close(
+        &basis.to_local(&momentum),
+        &[0.0, 0.0, momentum.dot(&momentum).sqrt()],
+    );
+    // y = z × x points away from the position for a prograde orbit
+    assert_close(&basis.to_local(&position), &[0.0, -7.0, 0.0]);
+}
+
+#[test]
+fn test_handedness() {
+    let (x, y) = (vector([0.0, 1.0, 0.0]), vector([1.0, 0.0, 0.0]));
+    let basis = OrthonormalLinearBasis::from_axes(&[(0, &x), (1, &y)]).unwrap();
+    assert_close(&basis.to_local(&vector([0.0, 0.0, 1.0])), &[0.0, 0.0, -1.0]);
+
+    // The secondary vector only fixes the half-plane of its axis
+    let tilted = vector([1.0, 5.0, 0.0]);
+    let same = OrthonormalLinearBasis::from_axes(&[(0, &x), (1, &tilted)]).unwrap();
+    assert_close(&same.to_local(&tilted), &[5.0, 1.0, 0.0]);
+
+    // A single vector is completed from the reference basis
+    let single = OrthonormalLinearBasis::from_axes(&[(1, &vector([0.0, 0.0, 2.0]))]).unwrap();
+    assert_close(&single.to_local(&vector([0.0, 0.0, 1.0])), &[0.0, 1.0, 0.0]);
+    assert_close(&single.to_local(&vector([1.0, 0.0, 0.0])), &[1.0, 0.0, 0.0]);
+
+    let plane =
+        OrthonormalLinearBasis::from_axes(&[(1, &V2::_from_raw([1.0, 1.0].into()))]).unwrap();
+    let unit = V2::_from_raw([1.0, -1.0].into());
+    assert_close(&plane.to_local(&unit), &[2.0f64.sqrt(), 0.0]);
+
+    // A left-handed basis, positive in the reversed orientation
+    let left = Orientation::<V3>::reference().reversed();
+    let (x, y) = (vector([1.0, 0.0, 0.0]), vector([0.0, 1.0, 0.0]));
+    let mirrored = LinearBasis::orthonormal_from_axes(&[(0, &x), (1, &y)], &left).unwrap();
+    assert_close(
+        &mirrored.to_local(&vector([1.0, 2.0, 3.0])),
+        &[1.0, 2.0, -3.0],
+    );
+    assert!(!Orientation::of_basis(&mirrored).is_reference());
+    let right = LinearBasis::orthonormal_from_axes(&[(0, &x), (1, &y)], &left.reversed()).unwrap();
+    assert_close(&right.to_local(&vector([1.0, 2.0, 3.0])), &[1.0, 2.0, 3.0]);
+    let z = vector([0.0, 0.0, 1.0]);
+    assert_eq!(
+        LinearBasis::orthonormal_from_axes(&[(0, &x), (1, &y), (2, &z)], &left).unwrap_err(),
+        Error::LeftHanded
+    );
+}
+
+#[test]
+fn test_degenerate_inputs() {
+    let (x, z) = (vector([1.0, 0.0, 0.0]), vector([0.0, 0.0, 1.0]));
+    let zero = vector([0.0; 3]);
+    let parallel = vector([-2.0, 0.0, 0.0]);
+    let y = vector([0.0, 1.0, 0.0]);
+
+    let error = |axes: &[(usize, &V3)]| OrthonormalLinearBasis::from_axes(axes).unwrap_err();
+    assert_eq!(error(&[(0, &zero)]), Error::ZeroVector);
+    assert_eq!(error(&[(0, &x), (1, &parallel)]), Error::Degenerate);
+    assert_eq!(error(&[(0, &x), (0, &z)]), Error::InvalidAxis);
+    assert_eq!(error(&[(3, &x)]), Error::InvalidAxis);
+    assert_eq!(error(&[(0, &x), (1, &z), (2, &y)]), Error::LeftHanded);
+    assert!(OrthonormalLinearBasis::from_axes(&[(0, &x), (1, &y), (2, &z)]).is_ok());
+}
+
+#[test]
+fn test_linear_basis_inverse() {
+    let e = OrthonormalLinearBasis::<V3> {
+        _from_origin: Rotation3D::identity(),
+    };
+    let map =
+        LinearMap::<V3, V3>::from_local(&e, &e, &[2.0, 1.0, 0.0, 0.0, 1.0, 0.5, 0.3, 0.0, 1.0]);
+    let skew = LinearBasis {
+        _from_origin: map.to_group().unwrap(),
+    };
+
+    // Basis vectors and dual basis covectors are biorthogonal
+    let (vectors, dual) = (skew.vectors(), skew.dual_vectors());
+    for (i, alpha) in dual.iter().enumerate() {
+        let pairings: Vec<f64> = vectors.iter().map(|v| v.pair_with(alpha)).collect();
+        let mut expected = [0.0; 3];
+        expected[i] = 1.0;
+        assert_close(&pairings, &expected);
+    }
+
+    // Products and inverses keep their cached inverses consistent
+    let v = vector([1.0, -2.0, 0.5]);
+    let twice = LinearBasis {
+        _from_origin: skew._from_origin.multiply(&skew._from_origin),
+    };
+    assert_close(
+        &e.to_local(&twice.from_local(&twice.to_local(&v))),
+        &[1.0, -2.0, 0.5],
+    );
+    let once = skew.to_local(&v);
+    assert_close(
+        &twice.to_local(&v),
+        &skew.to_local(&vector([once[0], once[1], once[2]])),
+    );
+    let inverse = LinearBasis {
+        _from_origin: skew._from_origin.inverse(),
+    };
+    assert_close(
+        &inverse.to_local(&vector([once[0], once[1], once[2]])),
+        &[1.0, -2.0, 0.5],
+    );
+}
+
+#[test]
+fn test_fallible_constructions() {
+    let (x, y) = (vector([1.0, 0.0, 0.0]), vector([0.0, 1.0, 0.0]));
+    let nearly_x = vector([1.0, 1e-10, 0.0]);
+    let z = vector([0.0, 0.0, 1.0]);
+
+    let basis = LinearBasis::try_from_vectors(&[x.clone(), y.clone(), z.clone()]).unwrap();
+    assert_close(&basis.to_local(&vector([1.0, 2.0, 3.0])), &[1.0, 2.0, 3.0]);
+    let error = |vectors: &[V3]| LinearBasis::try_from_vectors(vectors).unwrap_err();
+    assert_eq!(error(&[x.clone(), x.clone(), z.clone()]), Error::Singular);
+    assert_eq!(
+        error(&[x.clone(), nearly_x.clone(), z.clone()]),
+        Error::IllConditioned
+    );
+    assert_eq!(error(&[x.clone(), y.clone()]), Error::DimensionMismatch);
+
+    let dual = V3::try_dual_basis(&[x.clone(), vector([1.0, 1.0, 0.0]), z.clone()].into()).unwrap();
+    assert_close(&[x.pair_with(&dual[0]), x.pair_with(&dual[1])], &[1.0, 0.0]);
+    assert_close(
+        &[y.pair_with(&dual[0]), y.pair_with(&dual[1])],
+        &[-1.0, 1.0],
+    );
+    assert_eq!(
+        V3::try_dual_basis(&[x.clone(), x.clone(), z.clone()].into()).unwrap_err(),
+        Error::Singular
+    );
+
+    let e = OrthonormalLinearBasis::<V3> {
+        _from_origin: Rotation3D::identity(),
+    };
+    let projection =
+        LinearMap::<V3, V3>::from_local(&e, &e, &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0]);
+    assert_eq!(projection.try_inverse().unwrap_err(), Error::Singular);
+    assert_eq!(projection.try_to_group().unwrap_err(), Error::Singular);
+
+    // Nearly singular maps are only rejected by the conditioned constructions
+    let squeeze =
+        LinearMap::<V3, V3>::from_local(&e, &e, &[1.0, 0.0, 0.0, 0.0, 1e-10, 0.0, 0.0, 0.0, 1.0]);
+    assert_close(
+        &squeeze.inverse().unwrap().to_local(&e, &e),
+        &[1.0, 0.0, 0.0, 0.0, 1e10, 0.0, 0.0, 0.0, 1.0],
+    );
+    assert!(squeeze.to_group().is_some());
+    assert_eq!(squeeze.try_inverse().unwrap_err(), Error::IllConditioned);
+    assert_eq!(squeeze.try_to_group().unwrap_err(), Error::IllConditioned);
+
+    let shear = GeneralLinearGroup::<V3>::try_from_matrix(Matrix3::new(
+        1.0, 2.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0,
+    ))
+    .unwrap();
+    assert_close(
+        &e.to_local(&shear.inverse().act_on(&vector([2.0, 1.0, 0.0]))),
+        &[0.0, 1.0, 0.0],
+    );
+    assert_eq!(
+        GeneralLinearGroup::<V3>::try_from_matrix(Matrix3::new(
+            1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0,
+        ))
+        .unwrap_err(),
+        Error::Singular
+    );
+
+    assert_eq!(
+        Rotation3D::try_from_axis_angle(&vector([0.0; 3]), 1.0).unwrap_err(),
+        Error::ZeroVector
+    );
+    assert_eq!(
+        Rotation3D::try_from_axis_angle(&vector([f64::NAN, 0.0, 1.0]), 1.0).unwrap_err(),
+        Error::NotFinite
+    );
+    assert_eq!(
+        Rotation3D::try_from_axis_angle(&z, f64::INFINITY).unwrap_err(),
+        Error::NotFinite
+    );
+    // Tiny axes still give a direction, even where their squared length underflows
+    let tiny = Rotation3D::try_from_axis_angle(&vector([0.0, 1e-170, 1e-170]), FRAC_PI_2).unwrap();
+    assert_close(
+        &e.to_local(&tiny.act_on(&x)),
+        &[0.0, FRAC_1_SQRT_2, -FRAC_1_SQRT_2],
+    );
+    assert!(Rotation3D::try_from_axis_angle(&z, 1.0).is_ok());
+}
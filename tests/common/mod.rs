@@ -0,0 +1,52 @@
+// Fixtures and assertions shared by the integration tests, each of which uses only some of them
+#![allow(dead_code)]
+
+use std::f64::consts::FRAC_PI_2;
+
+use geometrica::euclidean::*;
+use geometrica::linear::basis::OrthonormalLinearBasis;
+
+pub type V3 = Vector<U3>;
+
+pub fn vector(components: [f64; 3]) -> V3 {
+    V3::_from_raw(components.into())
+}
+
+pub fn point(components: [f64; 3]) -> EuclideanSpace<U3> {
+    EuclideanSpace {
+        _from_origin: vector(components),
+    }
+}
+
+pub fn reference() -> OrthonormalLinearBasis<V3> {
+    OrthonormalLinearBasis {
+        _from_origin: Rotation3D::identity(),
+    }
+}
+
+// Basis rotated by -90 degrees about z, so that components rotate by +90 degrees
+pub fn rotated() -> OrthonormalLinearBasis<V3> {
+    OrthonormalLinearBasis {
+        _from_origin: Rotation3D::from_axis_angle(&vector([0.0, 0.0, 1.0]), FRAC_PI_2),
+    }
+}
+
+pub fn assert_within(actual: &[f64], expected: &[f64], tolerance: f64) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < tolerance, "{:?} != {:?}", actual, expected);
+    }
+}
+
+pub fn assert_close(actual: &[f64], expected: &[f64]) {
+    assert_within(actual, expected, 1e-12);
+}
+
+pub fn assert_vector_close(actual: &V3, expected: &V3) {
+    assert!(
+        (actual._get_raw() - expected._get_raw()).norm() < 1e-12,
+        "{:?} != {:?}",
+        actual,
+        expected
+    );
+}
@@ -0,0 +1,134 @@
+use geometrica::Error;
+use geometrica::euclidean::*;
+use geometrica::lie::GroupAction;
+use geometrica::linear::GeneralLinearGroup;
+use geometrica::linear::basis::LinearBasis;
+use nalgebra::DVector;
+
+// State vectors whose size is only known at runtime
+type State = Vector<Dyn>;
+
+fn state(components: &[f64]) -> State {
+    State::_from_raw(DVector::from_column_slice(components))
+}
+
+#[test]
+fn test_runtime_sized_vectors() {
+    let zero = State::zero_with_dim(5).unwrap();
+    assert_eq!(zero.dim(), 5);
+
+    let (a, b) = (state(&[1.0, 2.0, 3.0, 4.0]), state(&[0.5, 0.0, -1.0, 2.0]));
+    assert_eq!(a.dot(&b), 5.5);
+    assert_eq!((&a + &b)._get_raw().as_slice(), &[1.5, 2.0, 2.0, 6.0]);
+    assert_eq!(a.multiply(&b.inverse())._get_raw(), (&a - &b)._get_raw());
+
+    let alpha = Covector::<Dyn>::_from_raw(DVector::from_column_slice(&[1.0, 0.0, 0.0, 1.0]));
+    assert_eq!(a.pair_with(&alpha), 5.0);
+
+    // Points of a runtime-sized affine space
+    let p = AffineSpace {
+        _from_origin: a.clone(),
+    };
+    assert_eq!((&(&p + &b) - &p)._get_raw(), b._get_raw());
+}
+
+#[test]
+fn test_runtime_sized_bases() {
+    let basis = LinearBasis::try_from_vectors(&[
+        state(&[1.0, 0.0, 0.0]),
+        state(&[1.0, 1.0, 0.0]),
+        state(&[0.0, 0.0, 2.0]),
+    ])
+    .unwrap();
+    assert_eq!(basis.dim(), 3);
+
+    let v = state(&[3.0, 1.0, 4.0]);
+    let components = basis.to_local_vec(&v);
+    assert_eq!(components, vec![2.0, 1.0, 2.0]);
+    assert_eq!(basis.from_local_slice(&components)._get_raw(), v._get_raw());
+
+    assert_eq!(
+        basis.try_to_local_vec(&state(&[1.0, 2.0])).unwrap_err(),
+        Error::DimensionMismatch
+    );
+    assert_eq!(
+        basis
+            .try_from_local_slice(&[1.0, 2.0, 3.0, 4.0])
+            .unwrap_err(),
+        Error::DimensionMismatch
+    );
+
+    let error = |vectors: &[State]| LinearBasis::try_from_vectors(vectors).unwrap_err();
+    assert_eq!(
+        error(&[state(&[1.0, 0.0]), state(&[0.0, 1.0, 0.0])]),
+        Error::DimensionMismatch
+    );
+    assert_eq!(
+        error(&[state(&[1.0, 0.0, 0.0]), state(&[0.0, 1.0, 0.0])]),
+        Error::DimensionMismatch
+    );
+    assert_eq!(
+        error(&[state(&[1.0, 2.0]), state(&[2.0, 4.0])]),
+        Error::Singular
+    );
+}
+
+#[test]
+fn test_runtime_sized_groups() {
+    let identity = GeneralLinearGroup::<State>::identity_with_dim(4).unwrap();
+    assert_eq!(identity.dim(), 4);
+    let v = state(&[1.0, -2.0, 3.0, 0.5]);
+    assert_eq!(identity.act_on(&v)._get_raw(), v._get_raw());
+
+    let basis = LinearBasis::try_from_vectors(&[state(&[2.0, 0.0]), state(&[1.0, 1.0])]).unwrap();
+    let g = &basis._from_origin;
+    let round_trip = g.multiply(&g.inverse());
+    let w = state(&[3.0, -1.0]);
+    assert!((round_trip.act_on(&w)._get_raw() - w._get_raw()).norm() < 1e-12);
+}
+
+#[test]
+fn test_mismatched_sizes() {
+    let (a, b) = (state(&[1.0, 2.0, 3.0]), state(&[1.0, 2.0]));
+    assert_eq!(a.try_dot(&b).unwrap_err(), Error::DimensionMismatch);
+    assert_eq!(a.try_add(&b).unwrap_err(), Error::DimensionMismatch);
+    assert_eq!(a.try_sub(&b).unwrap_err(), Error::DimensionMismatch);
+    assert_eq!(a.try_multiply(&b).unwrap_err(), Error::DimensionMismatch);
+    assert_eq!(a.try_dot(&a).unwrap(), 14.0);
+    assert_eq!(
+        a.try_add(&a).unwrap()._get_raw().as_slice(),
+        &[2.0, 4.0, 6.0]
+    );
+
+    let alpha = Covector::<Dyn>::_from_raw(DVector::from_column_slice(&[1.0, 1.0]));
+    assert_eq!(
+        a.try_pair_with(&alpha).unwrap_err(),
+        Error::DimensionMismatch
+    );
+    assert_eq!(b.try_pair_with(&alpha).unwrap(), 3.0);
+
+    let (g, h) = (
+        GeneralLinearGroup::<State>::identity_with_dim(3).unwrap(),
+        GeneralLinearGroup::<State>::identity_with_dim(2).unwrap(),
+    );
+    assert_eq!(g.try_multiply(&h).unwrap_err(), Error::DimensionMismatch);
+    assert_eq!(g.try_act_on(&b).unwrap_err(), Error::DimensionMismatch);
+    assert_eq!(g.try_act_on(&a).unwrap()._get_raw(), a._get_raw());
+
+    // Translations of affine points check the sizes too
+    let p = AffineSpace { _from_origin: a };
+    assert_eq!(b.try_act_on(&p).unwrap_err(), Error::DimensionMismatch);
+}
+
+#[test]
+fn test_static_dimension_is_checked() {
+    assert_eq!(
+        Vector::<U3>::zero_with_dim(4).unwrap_err(),
+        Error::DimensionMismatch
+    );
+    assert_eq!(
+        GeneralLinearGroup::<Vector<U3>>::identity_with_dim(2).unwrap_err(),
+        Error::DimensionMismatch
+    );
+    assert_eq!(Vector::<U3>::zero_with_dim(3).unwrap().dim(), 3);
+}
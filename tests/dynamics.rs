@@ -0,0 +1,241 @@
+use std::f64::consts::FRAC_PI_2;
+
+use geometrica::Error;
+use geometrica::dynamics::{Inertia, RigidBody, Wrench};
+use geometrica::euclidean::*;
+use geometrica::kinematics::Kinematics;
+use geometrica::lie::{GroupAction, LieAlgebra, LieAlgebraDual};
+use geometrica::linear::basis::OrthonormalLinearBasis;
+use nalgebra::{Vector3, Vector6};
+
+mod common;
+
+use common::{V3, assert_close, point, reference, rotated};
+
+fn z_axis() -> V3 {
+    reference().from_local(&[0.0, 0.0, 1.0].into())
+}
+
+fn diagonal(a: f64, b: f64, c: f64) -> [f64; 9] {
+    [a, 0.0, 0.0, 0.0, b, 0.0, 0.0, 0.0, c]
+}
+
+#[test]
+fn test_components_and_rotation() {
+    let (e, b) = (reference(), rotated());
+    let inertia = Inertia::from_principal_moments(&e, [1.0, 2.0, 3.0]).unwrap();
+    assert_close(&inertia.to_local(&b), &diagonal(2.0, 1.0, 3.0));
+
+    let rotation = Rotation3D::from_axis_angle(&z_axis(), FRAC_PI_2);
+    assert_close(
+        &inertia.rotate(&rotation).to_local(&e),
+        &diagonal(2.0, 1.0, 3.0),
+    );
+
+    assert_eq!(
+        Inertia::<V3>::from_local(&e, &[1.0, 2.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]).unwrap_err(),
+        Error::NotSymmetric
+    );
+    assert_eq!(
+        Inertia::<V3>::from_local(&e, &diagonal(1.0, -1.0, 1.0)).unwrap_err(),
+        Error::NotPositive
+    );
+    assert_eq!(
+        Inertia::<V3>::from_local(&e, &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0]).unwrap_err(),
+        Error::DimensionMismatch
+    );
+}
+
+#[test]
+fn test_parallel_axis() {
+    // Thin rod of mass 3 and length 2 along z, about its center and about its end
+    let e = reference();
+    let center = EuclideanSpace::<U3> {
+        _from_origin: e.from_local(&[1.0, 1.0, 0.0].into()),
+    };
+    let end = EuclideanSpace::<U3> {
+        _from_origin: e.from_local(&[1.0, 1.0, 1.0].into()),
+    };
+    let rod = Inertia::from_principal_moments(&e, [1.0, 1.0, 1e-6]).unwrap();
+    let shifted = rod.parallel_axis(3.0, &center, &end);
+    assert_close(&shifted.to_local(&e), &diagonal(4.0, 4.0, 1e-6));
+    assert_close(
+        &rod.parallel_axis(3.0, &center, &center).to_local(&e),
+        &rod.to_local(&e),
+    );
+
+    let offset = EuclideanSpace::<U3> {
+        _from_origin: e.from_local(&[2.0, 2.0, 0.0].into()),
+    };
+    let shifted = rod.parallel_axis(3.0, &center, &offset);
+    assert_close(
+        &shifted.to_local(&e),
+        &[4.0, -3.0, 0.0, -3.0, 4.0, 0.0, 0.0, 0.0, 6.0 + 1e-6],
+    );
+}
+
+#[test]
+fn test_principal_axes() {
+    let tilted = OrthonormalLinearBasis {
+        _from_origin: Rotation3D::from_axis_angle(
+            &reference().from_local(&[1.0, -2.0, 0.5].into()),
+            0.7,
+        ),
+    };
+    let inertia = Inertia::from_principal_moments(&tilted, [3.0, 1.0, 2.0]).unwrap();
+    let (moments, axes) = inertia.principal_axes();
+    assert_close(&moments, &[1.0, 2.0, 3.0]);
+    assert_close(&inertia.to_local(&axes), &diagonal(1.0, 2.0, 3.0));
+
+    // The axis of least inertia is the second axis of the tilted basis, up to sign
+    let axis = axes.from_local(&[1.0, 0.0, 0.0].into());
+    assert_close(&[tilted.to_local(&axis)[1].abs()], &[1.0]);
+}
+
+#[test]
+fn test_angular_momentum() {
+    let e = reference();
+    let inertia = Inertia::from_local(&e, &[2.0, 1.0, 0.0, 1.0, 2.0, 0.0, 0.0, 0.0, 5.0]).unwrap();
+    let omega = e.from_local(&[1.0, 0.0, 2.0].into());
+    assert_close(
+        &e.to_local(&inertia.angular_momentum(&omega)),
+        &[2.0, 1.0, 10.0],
+    );
+    assert_close(&[inertia.kinetic_energy(&omega)], &[11.0]);
+
+    // The angular momentum does not depend on the basis used to describe the body
+    let b = rotated();
+    let same = Inertia::from_local(&b, &inertia.to_local(&b)).unwrap();
+    assert_close(
+        &b.to_local(&same.angular_momentum(&omega)),
+        &b.to_local(&inertia.angular_momentum(&omega)),
+    );
+}
+
+// Space velocity of a body turning at `w` whose point `c` moves with `v`
+fn twist(w: [f64; 3], c: [f64; 3], v: [f64; 3]) -> LieAlgebra<Motion3D> {
+    let (w, c, v) = (Vector3::from(w), Vector3::from(c), Vector3::from(v));
+    let at_origin = v - w.cross(&c);
+    LieAlgebra::_from_raw(Vector6::new(
+        w[0],
+        w[1],
+        w[2],
+        at_origin[0],
+        at_origin[1],
+        at_origin[2],
+    ))
+}
+
+#[test]
+fn test_newton_euler() {
+    // A unit cube of mass 6 whose center is 1 m along the body x axis, turned a quarter turn
+    // about z and moved up, so that its center is at (3, 1, 2)
+    let inertia = Inertia::from_principal_moments(&reference(), [1.0, 1.0, 1.0]).unwrap();
+    let body = RigidBody::new(6.0, point([1.0, 0.0, 0.0]), inertia.clone());
+    assert_eq!(body.mass(), 6.0);
+    for mass in [0.0, -6.0, f64::NAN] {
+        assert_eq!(
+            RigidBody::try_new(mass, point([1.0, 0.0, 0.0]), inertia.clone()).unwrap_err(),
+            Error::NotPositive
+        );
+    }
+    let pose = Motion3D::from_parts(
+        &Rotation3D::from_axis_angle(&z_axis(), FRAC_PI_2),
+        &reference().from_local(&[3.0, 0.0, 2.0].into()),
+    );
+    let state = Kinematics::stationary(EuclideanSpace::<U3>::reference_frame().act(&pose));
+    let center = point([3.0, 1.0, 2.0]);
+
+    // A force through the center of mass only accelerates it
+    let push = Wrench::from_force(&reference().from_local(&[0.0, 0.0, 12.0].into()), &center);
+    let acceleration = body.acceleration(&state, &push);
+    let moved = state.clone().with_acceleration(acceleration);
+    assert_close(
+        moved.angular_acceleration()._get_raw().as_slice(),
+        &[0.0; 3],
+    );
+    assert_close(
+        moved.point_acceleration(&center)._get_raw().as_slice(),
+        &[0.0, 0.0, 2.0],
+    );
+
+    // Off the center it also spins the body up about the center
+    let twisted = Wrench::from_force(
+        &reference().from_local(&[0.0, 0.0, 12.0].into()),
+        &point([4.0, 1.0, 2.0]),
+    );
+    let moved = state
+        .clone()
+        .with_acceleration(body.acceleration(&state, &twisted));
+    assert_close(
+        moved.angular_acceleration()._get_raw().as_slice(),
+        &[0.0, -12.0, 0.0],
+    );
+    assert_close(
+        moved.point_acceleration(&center)._get_raw().as_slice(),
+        &[0.0, 0.0, 2.0],
+    );
+    assert_close(
+        twisted.torque_about(&center)._get_raw().as_slice(),
+        &[0.0, -12.0, 0.0],
+    );
+
+    // Energy and momentum of a body spinning about its center and moving along x
+    let moving = Kinematics::new(
+        state.point.clone(),
+        twist([0.0, 0.0, 2.0], [3.0, 1.0, 2.0], [1.0, 0.0, 0.0]),
+    );
+    assert_close(&[body.kinetic_energy(&moving)], &[5.0]);
+    let momentum = body.momentum(&moving);
+    assert_close(momentum.force()._get_raw().as_slice(), &[6.0, 0.0, 0.0]);
+    assert_close(
+        momentum.torque_about(&center)._get_raw().as_slice(),
+        &[0.0, 0.0, 2.0],
+    );
+    assert_close(&[momentum.pair(&moving.velocity)], &[10.0]);
+}
+
+#[test]
+fn test_torque_free_precession() {
+    // Symmetric top with its center of mass on its axis, spinning off the axis and drifting
+    let inertia = Inertia::from_principal_moments(&reference(), [1.0, 1.0, 2.0]).unwrap();
+    let body = RigidBody::new(3.0, point([0.0, 0.0, 0.5]), inertia);
+    let mut state = Kinematics::new(
+        EuclideanSpace::<U3>::reference_frame(),
+        twist([0.3, 0.0, 1.0], [0.0, 0.0, 0.5], [0.1, 0.0, 0.0]),
+    );
+    let no_wrench = LieAlgebraDual::zero();
+    let energy = body.kinetic_energy(&state);
+    let momentum = body.momentum(&state);
+
+    // Midpoint steps of the Newton–Euler equations
+    let (dt, steps) = (1e-3, 1000);
+    for _ in 0..steps {
+        let acceleration = body.acceleration(&state, &no_wrench);
+        let half = state.advance_with(&acceleration, dt / 2.0);
+        state = state.advance_with(&body.acceleration(&half, &no_wrench), dt);
+    }
+
+    assert!((body.kinetic_energy(&state) - energy).abs() < 1e-6);
+    let drift = body.momentum(&state)._get_raw() - momentum._get_raw();
+    assert!(drift.norm() < 1e-6);
+
+    // The axis turns about the angular momentum at |L| / I1, keeping its angle to it
+    let center = |state: &Kinematics<Motion3D>| {
+        let pose = state.point._from_origin.inverse();
+        GroupAction::<EuclideanSpace<U3>>::act_on(&pose, body.center_of_mass())
+    };
+    let l = *momentum.torque_about(&center(&state))._get_raw();
+    let axis = state
+        .point
+        ._from_origin
+        .inverse()
+        .rotation()
+        .act_on(&z_axis());
+    let (start, end) = (Vector3::z(), *axis._get_raw());
+    let unit = l.normalize();
+    assert!((start.dot(&unit) - end.dot(&unit)).abs() < 1e-6);
+    let (p, q) = (start - unit * start.dot(&unit), end - unit * end.dot(&unit));
+    let angle = unit.dot(&p.cross(&q)).atan2(p.dot(&q));
+    assert!((angle - l.norm() * dt * steps as f64).abs() < 1e-6);
+}
@@ -1,18 +1,26 @@
 use std::f64::consts::PI;
 
 use geometrica::euclidean::*;
+use geometrica::lie::GroupAction;
+use geometrica::linear::basis::{LinearBasis, OrthonormalLinearBasis};
+use geometrica::linear::group::orthogonal::SpecialOrthogonalGroup2D;
+
+mod common;
+
+use common::{assert_close, point};
 
 #[test]
 fn test_euclid3() {
     {
         let e = EuclideanSpace::<U3>::reference_frame();
-        let v = e.from_local(&[1.0, 2.0, 3.0]);
-        let r = Rotation3D::from_axis_angle(&e.basis[0], PI / 2.0);
-        let m = Motion3D::from_rotation(&r, &e.origin);
+        let v = e.from_local(&[1.0, 2.0, 3.0].into());
+        let x = e.basis().from_local(&[1.0, 0.0, 0.0].into());
+        let r = Rotation3D::from_axis_angle(&x, PI / 2.0);
+        let m = Motion3D::from_rotation(&r, &e.origin());
         let b = e.act(&m);
 
-        println!("v: {:?}", e.to_local(&v));
-        println!("b: {:?}", b.to_local(&v));
+        assert_close(&e.to_local(&v), &[1.0, 2.0, 3.0]);
+        assert_close(&b.to_local(&v), &[1.0, 3.0, -2.0]);
     }
 
     // let v1 = e.clone().from_local([1.0, 2.0, 3.0]);
@@ -21,3 +29,151 @@ fn test_euclid3() {
     // let t2 = t.multiply(&t);
     // assert_eq!(t2.raw, [0.0, 0.0, 0.0]);
 }
+
+#[test]
+fn test_affine_frames() {
+    let basis = OrthonormalLinearBasis::<Vector<U3>> {
+        _from_origin: Rotation3D::from_axis_angle(&point([0.0, 0.0, 1.0])._from_origin, -PI / 2.0),
+    };
+    let frame = OrthonormalAffineFrame::new(&point([1.0, 2.0, 3.0]), &basis);
+    assert_close(
+        frame.origin()._from_origin._get_raw().as_slice(),
+        &[1.0, 2.0, 3.0],
+    );
+    let x = frame.basis().from_local(&[1.0, 0.0, 0.0].into());
+    assert_close(x._get_raw().as_slice(), &[0.0, 1.0, 0.0]);
+    let p = point([1.0, 3.0, 4.0]);
+    assert_close(&frame.to_local(&p), &[1.0, 0.0, 1.0]);
+    assert_close(
+        frame
+            .from_local(&[1.0, 0.0, 1.0].into())
+            ._from_origin
+            ._get_raw()
+            .as_slice(),
+        &[1.0, 3.0, 4.0],
+    );
+
+    // Moving the frame moves its origin, and points moved with it keep their components
+    let m = Motion3D::from_rotation(&basis._from_origin, &point([0.0, 0.0, 0.0]));
+    let moved = frame.act(&m);
+    assert_close(
+        moved.origin()._from_origin._get_raw().as_slice(),
+        &[2.0, -1.0, 3.0],
+    );
+    let moved_p = GroupAction::<EuclideanSpace<U3>>::act_on(&m, &p);
+    assert_close(&moved.to_local(&moved_p), &[1.0, 0.0, 1.0]);
+
+    // Affine frames with any basis give the same components for orthonormal ones
+    let affine = AffineFrame::from(frame.clone());
+    assert_close(&affine.to_local(&p), &[1.0, 0.0, 1.0]);
+    assert_close(&affine.act(&m).to_local(&moved_p), &[1.0, 0.0, 1.0]);
+    let skewed = AffineFrame::new(
+        point([1.0, 0.0, 0.0]),
+        LinearBasis::try_from_vectors(&[
+            Vector::_from_raw([2.0, 0.0, 0.0].into()),
+            Vector::_from_raw([1.0, 1.0, 0.0].into()),
+            Vector::_from_raw([0.0, 0.0, 1.0].into()),
+        ])
+        .unwrap(),
+    );
+    assert_close(&skewed.to_local(&point([4.0, 1.0, 2.0])), &[1.0, 1.0, 2.0]);
+    assert_close(
+        skewed
+            .from_local(&[1.0, 1.0, 2.0].into())
+            ._from_origin
+            ._get_raw()
+            .as_slice(),
+        &[4.0, 1.0, 2.0],
+    );
+}
+
+fn raw(p: &EuclideanSpace<U3>) -> &[f64] {
+    p._from_origin._get_raw().as_slice()
+}
+
+fn moved(m: &Motion3D, p: &EuclideanSpace<U3>) -> EuclideanSpace<U3> {
+    GroupAction::<EuclideanSpace<U3>>::act_on(m, p)
+}
+
+#[test]
+fn test_motion_construction() {
+    let z = Vector::<U3>::_from_raw([0.0, 0.0, 1.0].into());
+    let origin = point([0.0, 0.0, 0.0]);
+
+    // A quarter turn about the line x = 1 advancing one unit along it
+    let m = Motion3D::from_screw(&point([1.0, 0.0, 0.0]), &z, PI / 2.0, 2.0 / PI);
+    assert_close(raw(&moved(&m, &origin)), &[1.0, -1.0, 1.0]);
+    let screw = m.screw().unwrap();
+    assert_close(raw(&screw.point), &[1.0, 0.0, 0.0]);
+    assert_close(screw.axis._get_raw().as_slice(), &[0.0, 0.0, 1.0]);
+    assert_close(
+        &[screw.angle, screw.translation, screw.pitch()],
+        &[PI / 2.0, 1.0, 2.0 / PI],
+    );
+    assert!(Motion3D::<f64>::identity().screw().is_none());
+    let slide = Motion3D::from_translation(&(&z * 2.0)).screw().unwrap();
+    assert_close(&[slide.angle, slide.translation], &[0.0, 2.0]);
+
+    // Rotation and translation recompose the motion
+    let parts = Motion3D::from_parts(&m.rotation(), &m.translation());
+    assert_close(
+        raw(&moved(&parts, &point([3.0, 2.0, 1.0]))),
+        raw(&moved(&m, &point([3.0, 2.0, 1.0]))),
+    );
+    assert_close(m.translation()._get_raw().as_slice(), &[1.0, -1.0, 1.0]);
+
+    // Looking from (1, 1, 0) along y, the y axis of the view points along -x
+    let view = Motion3D::look_at(&point([1.0, 1.0, 0.0]), &point([1.0, 3.0, 0.0]), &z);
+    let frame = EuclideanSpace::<U3>::reference_frame().act(&view);
+    assert_close(&frame.to_local(&point([1.0, 3.0, 0.0])), &[2.0, 0.0, 0.0]);
+    assert_close(&frame.to_local(&point([0.0, 1.0, 1.0])), &[0.0, 1.0, 1.0]);
+    assert!(Motion3D::try_look_at(&origin, &point([0.0, 0.0, 1.0]), &z).is_err());
+
+    // Homogeneous matrices depend on the frame, and convert back to the same motion
+    let reference = EuclideanSpace::<U3>::reference_frame();
+    let matrix = view.to_homogeneous(&reference);
+    assert_close(
+        matrix.as_slice(),
+        &[
+            0.0, 1.0, 0.0, 0.0, -1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0,
+        ],
+    );
+    let local = m.to_homogeneous(&frame);
+    let back = Motion3D::from_homogeneous(&frame, &local);
+    assert_close(
+        raw(&moved(&back, &point([3.0, 2.0, 1.0]))),
+        raw(&moved(&m, &point([3.0, 2.0, 1.0]))),
+    );
+    let mut sheared = matrix;
+    sheared[(0, 1)] = 0.5;
+    assert_eq!(
+        Motion3D::try_from_homogeneous(&reference, &sheared).unwrap_err(),
+        geometrica::Error::NotRigid
+    );
+}
+
+#[test]
+fn test_planar_motion_construction() {
+    let point = |x: f64, y: f64| EuclideanSpace::<U2> {
+        _from_origin: Vector::_from_raw([x, y].into()),
+    };
+    let turn = Motion2D::from_rotation(&Rotation2D::from_angle(PI / 2.0), &point(1.0, 1.0));
+    let center = turn.center().unwrap();
+    assert_close(center._from_origin._get_raw().as_slice(), &[1.0, 1.0]);
+    assert!(
+        Motion2D::from_translation(&point(1.0, 0.0)._from_origin)
+            .center()
+            .is_none()
+    );
+
+    let view = Motion2D::look_at(&point(1.0, 0.0), &point(1.0, 2.0));
+    let frame = EuclideanSpace::<U2>::reference_frame().act(&view);
+    assert_close(&frame.to_local(&point(0.0, 0.0)), &[0.0, 1.0]);
+    let matrix = turn.to_homogeneous(&frame);
+    let back = Motion2D::from_homogeneous(&frame, &matrix);
+    assert_close(&[back.rotation().angle()], &[PI / 2.0]);
+    assert_close(
+        back.translation()._get_raw().as_slice(),
+        turn.translation()._get_raw().as_slice(),
+    );
+}
@@ -0,0 +1,151 @@
+use std::f64::consts::FRAC_PI_3;
+
+use geometrica::Error;
+use geometrica::euclidean::*;
+use geometrica::linear::basis::{LinearBasis, OrthonormalLinearBasis};
+use geometrica::linear::metric::MinkowskiVector;
+use geometrica::linear::{Bivector, LinearMap, Metric, MetricVector, Orientation};
+use nalgebra::Matrix3;
+
+mod common;
+
+use common::{V3, assert_close, reference, vector};
+
+// Degenerate form, under which the third reference vector has no length
+#[derive(Clone, Debug)]
+struct Slab;
+
+impl Metric<U3, f64> for Slab {
+    fn gram() -> Matrix3<f64> {
+        Matrix3::new(1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0)
+    }
+}
+
+// Reflection through the xy plane
+fn mirror() -> LinearMap<V3, V3> {
+    let e = reference();
+    LinearMap::from_local(&e, &e, &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, -1.0])
+}
+
+#[test]
+fn test_cross_and_triple_products() {
+    let e = reference();
+    let right = Orientation::<V3>::reference();
+    let (x, y) = (vector([1.0, 0.0, 0.0]), vector([0.0, 1.0, 0.0]));
+    assert_close(&e.to_local(&right.cross(&x, &y)), &[0.0, 0.0, 1.0]);
+    assert_close(
+        &e.to_local(&right.reversed().cross(&x, &y)),
+        &[0.0, 0.0, -1.0],
+    );
+
+    let (a, b, c) = (
+        vector([1.0, 2.0, 3.0]),
+        vector([-1.0, 0.5, 2.0]),
+        vector([0.0, 1.0, -1.0]),
+    );
+    assert_close(&e.to_local(&right.cross(&a, &b)), &[2.5, -5.0, 2.5]);
+    assert_close(&[right.triple(&a, &b, &c)], &[a.dot(&right.cross(&b, &c))]);
+    assert_close(&[right.triple(&a, &b, &c)], &[-7.5]);
+    assert_close(&[right.cross(&a, &b).dot(&a)], &[0.0]);
+}
+
+#[test]
+fn test_reflections() {
+    let e = reference();
+    let right = Orientation::<V3>::reference();
+    let mirror = mirror();
+    let (a, b) = (vector([1.0, 2.0, 3.0]), vector([-1.0, 0.5, 2.0]));
+    let (ma, mb) = (mirror.apply(&a), mirror.apply(&b));
+
+    // The cross product is a pseudo-vector: it picks up a sign under reflections
+    assert_close(
+        &e.to_local(&right.cross(&ma, &mb)),
+        &e.to_local(&mirror.apply(&right.cross(&a, &b)).scale(-1.0)),
+    );
+    assert_close(
+        &[right.triple(&ma, &mb, &mirror.apply(&vector([0.0, 1.0, -1.0])))],
+        &[7.5],
+    );
+
+    // The wedge product is a true tensor, and so is the cross product with the reflected
+    // orientation
+    assert_close(
+        &Bivector::wedge(&ma, &mb).to_local(&e),
+        &Bivector::wedge(&a, &b).transform(&mirror).to_local(&e),
+    );
+    let left = right.push_forward(&mirror).unwrap();
+    let flatten =
+        LinearMap::<V3, V3>::from_local(&e, &e, &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0]);
+    assert_eq!(right.push_forward(&flatten).unwrap_err(), Error::Singular);
+    assert!(!left.is_reference());
+    assert_close(
+        &e.to_local(&left.cross(&ma, &mb)),
+        &e.to_local(&mirror.apply(&right.cross(&a, &b))),
+    );
+}
+
+#[test]
+fn test_orientation_of_bases() {
+    let z = vector([0.0, 0.0, 1.0]);
+    let rotated = OrthonormalLinearBasis {
+        _from_origin: Rotation3D::from_axis_angle(&z, FRAC_PI_3),
+    };
+    assert!(Orientation::of_basis(&rotated).is_reference());
+    assert!(Orientation::of_basis(&reference()).is_reference());
+
+    let mirrored = LinearBasis {
+        _from_origin: mirror().to_group().unwrap(),
+    };
+    assert!(!Orientation::of_basis(&mirrored).is_reference());
+}
+
+#[test]
+fn test_hodge_star() {
+    let e = reference();
+    let right = Orientation::<V3>::reference();
+    let bivector = Bivector::wedge(&vector([1.0, 2.0, 0.0]), &vector([0.0, 1.0, 1.0]));
+    assert_close(
+        &bivector.to_local(&e),
+        &[0.0, 1.0, 1.0, -1.0, 0.0, 2.0, -1.0, -2.0, 0.0],
+    );
+    let dual = right.hodge(&bivector);
+    assert_close(&e.to_local(&dual), &[2.0, -1.0, 1.0]);
+    assert_close(
+        &right.hodge_inverse(&dual).to_local(&e),
+        &bivector.to_local(&e),
+    );
+
+    // With an indefinite metric the star still inverts, with the metric raising the index
+    type Spacetime = MinkowskiVector<U3>;
+    let minkowski = Orientation::<Spacetime>::reference();
+    let (t, x) = (
+        Spacetime::_from_raw([1.0, 0.0, 0.0].into()),
+        Spacetime::_from_raw([0.0, 1.0, 0.0].into()),
+    );
+    let normal = minkowski.try_cross(&t, &x).unwrap();
+    assert_close(normal._get_raw().as_slice(), &[0.0, 0.0, 1.0]);
+    let star = minkowski
+        .try_hodge(&minkowski.try_hodge_inverse(&t).unwrap())
+        .unwrap();
+    assert_close(star._get_raw().as_slice(), &[1.0, 0.0, 0.0]);
+    assert_close(&[minkowski.volume(&[&t, &x, &normal]).unwrap()], &[1.0]);
+    assert_eq!(
+        minkowski.volume(&[&t, &x]).unwrap_err(),
+        Error::DimensionMismatch
+    );
+}
+
+#[test]
+fn test_hodge_star_of_degenerate_metric() {
+    type Degenerate = MetricVector<U3, Slab>;
+    let orientation = Orientation::<Degenerate>::reference();
+    let (x, y) = (
+        Degenerate::_from_raw([1.0, 0.0, 0.0].into()),
+        Degenerate::_from_raw([0.0, 1.0, 0.0].into()),
+    );
+    assert_eq!(orientation.try_cross(&x, &y).unwrap_err(), Error::Singular);
+    assert_eq!(
+        orientation.try_hodge_inverse(&x).unwrap_err(),
+        Error::Singular
+    );
+}
@@ -0,0 +1,323 @@
+use std::f64::consts::FRAC_PI_2;
+
+use geometrica::Error;
+use geometrica::euclidean::*;
+use geometrica::frames::{FrameGraph, Lazy, Reference, Relative, Representation, Represented};
+use geometrica::lie::GroupAction;
+use geometrica::linear::basis::OrthonormalLinearBasis;
+
+mod common;
+
+use common::{V3, assert_vector_close, vector};
+
+fn turn(axis: [f64; 3]) -> Rotation3D {
+    Rotation3D::from_axis_angle(&vector(axis), FRAC_PI_2)
+}
+
+// world ─┬─ body ── sensor
+//        └─ ground ── station
+fn graph() -> FrameGraph<Rotation3D> {
+    let mut graph = FrameGraph::new();
+    graph.add_root("world").unwrap();
+    graph.add("body", "world", turn([0.0, 0.0, 1.0])).unwrap();
+    graph.add("sensor", "body", turn([1.0, 0.0, 0.0])).unwrap();
+    graph.add("ground", "world", turn([0.0, 1.0, 0.0])).unwrap();
+    graph
+        .add("station", "ground", Rotation3D::identity())
+        .unwrap();
+    graph
+}
+
+#[test]
+fn test_transforms_through_common_ancestor() {
+    let graph = graph();
+    let v = vector([1.0, 2.0, 3.0]);
+
+    // Components in the sensor frame, applying the transforms down from the world
+    let world_to_sensor = graph.transform("world", "sensor").unwrap();
+    assert_vector_close(&world_to_sensor.act_on(&v), &vector([-2.0, -3.0, 1.0]));
+
+    // Up from the sensor to the world, then down to the station
+    let sensor_to_station = graph.transform("sensor", "station").unwrap();
+    assert_vector_close(
+        &sensor_to_station.act_on(&world_to_sensor.act_on(&v)),
+        &vector([3.0, 2.0, -1.0]),
+    );
+    assert_vector_close(
+        &graph.transform("body", "body").unwrap().act_on(&v),
+        &vector([1.0, 2.0, 3.0]),
+    );
+
+    // Frames relative to their root are charts of the root's space
+    let sensor: OrthonormalLinearBasis<V3> = graph.frame("sensor").unwrap();
+    let components = sensor.to_local(&v);
+    assert_vector_close(
+        &vector([components[0], components[1], components[2]]),
+        &vector([-2.0, -3.0, 1.0]),
+    );
+    assert_eq!(graph.root("station").unwrap(), "world");
+    assert_eq!(graph.parent("sensor").unwrap(), Some("body"));
+}
+
+#[test]
+fn test_cache_is_invalidated_on_update() {
+    let mut graph = graph();
+    let v = vector([1.0, 0.0, 0.0]);
+    graph.transform("sensor", "station").unwrap();
+    graph.transform("world", "ground").unwrap();
+    graph.transform("sensor", "body").unwrap();
+    assert_eq!(graph.cached(), 3);
+
+    // Only the transforms whose path goes through the body are dropped
+    graph.update("body", Rotation3D::identity()).unwrap();
+    assert_eq!(graph.cached(), 2);
+    assert_vector_close(
+        &graph.transform("world", "sensor").unwrap().act_on(&v),
+        &vector([1.0, 0.0, 0.0]),
+    );
+
+    graph.update("sensor", Rotation3D::identity()).unwrap();
+    assert_vector_close(
+        &graph.transform("station", "sensor").unwrap().act_on(&v),
+        &vector([0.0, 0.0, 1.0]),
+    );
+    assert_vector_close(
+        &graph.transform("sensor", "body").unwrap().act_on(&v),
+        &vector([1.0, 0.0, 0.0]),
+    );
+}
+
+#[test]
+fn test_graph_errors() {
+    let mut graph = graph();
+    graph.add_root("moon").unwrap();
+    graph.add("lander", "moon", turn([0.0, 0.0, 1.0])).unwrap();
+
+    assert_eq!(
+        graph.transform("lander", "sensor").unwrap_err(),
+        Error::Disconnected
+    );
+    assert_eq!(
+        graph.transform("lander", "mars").unwrap_err(),
+        Error::UnknownFrame
+    );
+    assert_eq!(graph.add_root("moon").unwrap_err(), Error::DuplicateFrame);
+    assert_eq!(
+        graph.update("moon", Rotation3D::identity()).unwrap_err(),
+        Error::NoParent
+    );
+    assert_eq!(
+        graph
+            .reparent("body", "sensor", Rotation3D::identity())
+            .unwrap_err(),
+        Error::FrameCycle
+    );
+    assert_eq!(
+        graph
+            .reparent("world", "world", Rotation3D::identity())
+            .unwrap_err(),
+        Error::FrameCycle
+    );
+
+    // Hanging the moon under the world connects the trees
+    graph
+        .reparent("moon", "world", Rotation3D::identity())
+        .unwrap();
+    assert_vector_close(
+        &graph
+            .transform("lander", "body")
+            .unwrap()
+            .act_on(&vector([1.0, 0.0, 0.0])),
+        &vector([1.0, 0.0, 0.0]),
+    );
+}
+
+#[test]
+fn test_translations() {
+    let mut graph = FrameGraph::<Translation<U3>>::new();
+    graph.add_root("earth").unwrap();
+    graph
+        .add("moon", "earth", vector([-3.8e8, 0.0, 0.0]))
+        .unwrap();
+    graph
+        .add("lander", "moon", vector([-1.7e6, 0.0, 0.0]))
+        .unwrap();
+    let offset = graph.transform("lander", "earth").unwrap();
+    assert_vector_close(&offset, &vector([3.817e8, 0.0, 0.0]));
+}
+
+type Point32 = EuclideanSpace<U3, f32>;
+type Shift32 = Translation<U3, f32>;
+
+fn point32(components: [f32; 3]) -> Point32 {
+    EuclideanSpace {
+        _from_origin: Vector::_from_raw(components.into()),
+    }
+}
+
+// Two points a quarter of a unit apart, given in a frame centred on the Moon
+fn place<'a, R: Representation<'a, Shift32, Point32>>(
+    moon: &'a Torsor<Shift32>,
+) -> [Represented<'a, R, Shift32, Point32>; 2] {
+    [
+        R::from_local(moon, point32([1.0, 0.0, 0.0])),
+        R::from_local(moon, point32([1.25, 0.0, 0.0])),
+    ]
+}
+
+#[test]
+fn test_lazy_representation_keeps_precision() {
+    let moon = Torsor {
+        _from_origin: Shift32::_from_raw([-3.844e8, 0.0, 0.0].into()),
+    };
+
+    // In single precision, reference components near the Moon are 32 units apart
+    let [a, b] = place::<Reference>(&moon);
+    assert_eq!((&b - &a)._get_raw()[0], 0.0);
+
+    let [a, b] = place::<Lazy>(&moon);
+    let difference = &b - &a;
+    assert_eq!(difference.local()._get_raw()[0], 0.25);
+    assert_eq!(difference.to_reference()._get_raw()[0], 0.25);
+    let c = &a + &(&difference * 2.0);
+    assert_eq!(c.local()._from_origin._get_raw()[0], 1.5);
+    assert!(core::ptr::eq(c.frame(), &moon));
+}
+
+type Motion32 = Motion3D<f32>;
+
+fn motion32(angle: f32, translation: [f32; 3]) -> Motion32 {
+    let axis = Vector::<U3, f32>::_from_raw([0.0, 0.0, 1.0].into());
+    Motion32::from_parts(
+        &Rotation3D::from_axis_angle(&axis, angle),
+        &Vector::_from_raw(translation.into()),
+    )
+}
+
+// Components of `motion32(angle, translation)` applied to `p`, in double precision
+fn moved64(angle: f64, translation: [f64; 3], p: [f64; 3]) -> [f64; 3] {
+    let (sin, cos) = angle.sin_cos();
+    [
+        cos * p[0] - sin * p[1] + translation[0],
+        sin * p[0] + cos * p[1] + translation[1],
+        p[2] + translation[2],
+    ]
+}
+
+#[test]
+fn test_lazy_representation_across_rotated_frames() {
+    // A frame on the Moon, and a lander frame a few metres away from it, both rotated
+    let moon = Torsor {
+        _from_origin: motion32(0.3, [-3.844e8, 1.2e7, 0.0]),
+    };
+    let from_moon = motion32(-1.1, [2.5, -4.0, 0.75]);
+    let lander = Torsor {
+        _from_origin: from_moon.multiply(&moon._from_origin),
+    };
+
+    let a = Relative::from_local(&moon, point32([1.0, 2.0, 0.25]));
+    let expected = moved64(-1.1, [2.5, -4.0, 0.75], [1.0, 2.0, 0.25]);
+    let error = |p: &Relative<Motion32, Point32>| {
+        let actual = p.local()._from_origin._get_raw();
+        (0..3)
+            .map(|i| (actual[i] as f64 - expected[i]).abs())
+            .fold(0.0, f64::max)
+    };
+
+    // The transform between the frames is exact to single precision when known directly
+    assert!(error(&a.express_via(&lander, &from_moon)) < 1e-5);
+
+    // but loses the metres when composed from the transforms from the reference
+    assert!(error(&a.express_in(&lander)) > 1.0);
+}
+
+#[test]
+fn test_relative_values_across_frames() {
+    let reference = Torsor {
+        _from_origin: Rotation3D::identity(),
+    };
+    let body = Torsor {
+        _from_origin: turn([0.0, 0.0, -1.0]),
+    };
+
+    // x in the body frame is y in the reference
+    let u = Relative::from_local(&body, vector([1.0, 0.0, 0.0]));
+    let v = Relative::from_local(&reference, vector([0.0, 1.0, 2.0]));
+    assert_vector_close(&u.to_reference(), &vector([0.0, 1.0, 0.0]));
+
+    // Operands are brought into a common frame explicitly, here by the transform from the
+    // reference to the body
+    let sum = &u + &v.express_via(&body, &body._from_origin);
+    assert!(core::ptr::eq(sum.frame(), &body));
+    assert_vector_close(sum.local(), &vector([2.0, 0.0, 2.0]));
+    assert_vector_close(&sum.to_reference(), &vector([0.0, 2.0, 2.0]));
+    assert_vector_close(
+        &(&v - &u.express_in(&reference)).to_reference(),
+        &vector([0.0, 0.0, 2.0]),
+    );
+    assert_vector_close(
+        &(-&u).express_in(&reference).local().clone(),
+        &vector([0.0, -1.0, 0.0]),
+    );
+
+    let w = Relative::from_reference(&body, &vector([0.0, 3.0, 0.0]));
+    assert_vector_close(w.local(), &vector([3.0, 0.0, 0.0]));
+}
+
+#[test]
+#[should_panic(expected = "Operands are in different frames")]
+fn test_relative_values_in_different_frames() {
+    let reference = Torsor {
+        _from_origin: Rotation3D::identity(),
+    };
+    let body = Torsor {
+        _from_origin: turn([0.0, 0.0, -1.0]),
+    };
+    let u = Relative::from_local(&body, vector([1.0, 0.0, 0.0]));
+    let v = Relative::from_local(&reference, vector([0.0, 1.0, 2.0]));
+    let _ = &u + &v;
+}
+
+struct World;
+struct Body;
+struct Sensor;
+
+#[test]
+fn test_tagged_frames() {
+    use geometrica::frames::{Tagged, Transform};
+
+    let body = Transform::<World, Body, Rotation3D>::new(turn([0.0, 0.0, 1.0]));
+    let sensor = Transform::<Body, Sensor, Rotation3D>::new(turn([1.0, 0.0, 0.0]));
+    let world_to_sensor = sensor.multiply(&body);
+
+    let v = Tagged::<World, V3>::new(vector([1.0, 0.0, 0.0]));
+    let in_sensor = world_to_sensor.act_on(&v);
+    assert_vector_close(in_sensor.local(), &vector([0.0, 0.0, 1.0]));
+    assert_vector_close(
+        sensor.act_on(&body.act_on(&v)).local(),
+        &vector([0.0, 0.0, 1.0]),
+    );
+    let back = world_to_sensor.inverse().act_on(&in_sensor);
+    assert_vector_close(&(&back - &v).into_inner(), &vector([0.0, 0.0, 0.0]));
+    assert_vector_close(
+        Transform::<Sensor, Sensor, Rotation3D>::identity()
+            .act_on(&in_sensor)
+            .local(),
+        &vector([0.0, 0.0, 1.0]),
+    );
+
+    // Tags are zero-sized and convert to the values given the frame
+    assert_eq!(size_of::<Tagged<Body, V3>>(), size_of::<V3>());
+    assert_eq!(size_of::<Transform<World, Body>>(), size_of::<Motion3D>());
+    let frame = Torsor {
+        _from_origin: body.group().clone(),
+    };
+    let relative = body.act_on(&v).relative_to(&frame);
+    assert_vector_close(&relative.to_reference(), &vector([1.0, 0.0, 0.0]));
+    assert_vector_close(
+        Transform::<World, Body, _>::from_frame(&frame)
+            .act_on(&v)
+            .local(),
+        &vector([0.0, 1.0, 0.0]),
+    );
+}
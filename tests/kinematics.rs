@@ -1,8 +1,16 @@
+use std::f64::consts::FRAC_PI_2;
+
 use geometrica::euclidean::*;
 use geometrica::kinematics::*;
+use geometrica::lie::{GroupAction, LieAlgebra};
+use nalgebra::Vector6;
+
+mod common;
+
+use common::{assert_vector_close, point, vector};
 
-type BodyFrame3D<K> = OrthonormalAffineFrame<3, EuclideanSpace<3, K>>;
-type RigidBodyKinematics3D<K> = Kinematics<6, BodyFrame3D<K>, Motion3D<K>>;
+type BodyFrame3D<K> = OrthonormalAffineFrame<Vector<U3, K>>;
+type RigidBodyKinematics3D<K> = Kinematics<Motion3D<K>>;
 
 struct Value {
     raw: f64,
@@ -19,8 +27,9 @@ impl HasRef<'_> {
 
 #[test]
 fn test_rigid_body_kinematics() {
-    let reference = EuclideanSpace::<3>::reference_frame();
+    let reference: BodyFrame3D<f64> = EuclideanSpace::<U3>::reference_frame();
     let body = RigidBodyKinematics3D::<f64>::stationary(reference);
+    assert_eq!(body.velocity._get_raw().norm(), 0.0);
 
     // let vel: RigidBodyKinematics3D<f64>::Velocity = body.velocity;
 
@@ -41,3 +50,218 @@ fn test_rigid_body_kinematics() {
 
     // let body = kinematics.transform(&reference);
 }
+
+// Where the body puts a point given in its own components
+fn place(body: &Torsor<Motion3D>, local: [f64; 3]) -> Vector<U3> {
+    body.from_local(&local.into())._from_origin
+}
+
+#[test]
+fn test_spin() {
+    let spin = LieAlgebra::_from_raw([0.0, 0.0, 1.0].into());
+    let body = Kinematics::<Rotation3D>::new(
+        Torsor {
+            _from_origin: Rotation3D::identity(),
+        },
+        spin,
+    );
+    let turned = body.advance(FRAC_PI_2);
+    let axis = turned
+        .point
+        ._from_origin
+        .inverse()
+        .act_on(&vector([1.0, 0.0, 0.0]));
+    assert_vector_close(&axis, &vector([0.0, 1.0, 0.0]));
+
+    // A body tipped over by a quarter turn about x spins about its own z axis in the body
+    // convention, which is the reference -y axis
+    let tipped = Torsor {
+        _from_origin: Rotation3D::from_axis_angle(&vector([1.0, 0.0, 0.0]), -FRAC_PI_2),
+    };
+    let body =
+        Kinematics::<Rotation3D, Body>::with_convention(tipped.clone(), body.velocity.clone());
+    let space = Kinematics::<Rotation3D, Space>::with_convention(
+        tipped,
+        LieAlgebra::_from_raw([0.0, -1.0, 0.0].into()),
+    );
+    let x = vector([1.0, 0.0, 0.0]);
+    for t in [0.3, 1.0, 2.5] {
+        let (body, space) = (body.advance(t), space.advance(t));
+        assert_vector_close(
+            &body.point._from_origin.inverse().act_on(&x),
+            &space.point._from_origin.inverse().act_on(&x),
+        );
+    }
+
+    // Spinning up uniformly turns by ω t + α t² / 2
+    let spin_up = LieAlgebra::_from_raw([0.0, 0.0, 2.0].into());
+    let turned = Kinematics::<Rotation3D>::stationary(Torsor {
+        _from_origin: Rotation3D::identity(),
+    })
+    .advance_with(&spin_up, 1.5);
+    assert_vector_close(
+        &turned.point._from_origin.inverse().scaled_axis(),
+        &vector([0.0, 0.0, 2.25]),
+    );
+    assert_vector_close(
+        &Vector::_from_raw(*turned.velocity._get_raw()),
+        &vector([0.0, 0.0, 3.0]),
+    );
+}
+
+#[test]
+fn test_screw_motion() {
+    // Turning at 1 rad/s about the line x = 1 while advancing along it at 0.5 m/s. The point of
+    // the body at the reference origin moves with v = -ω × c + h ω.
+    let (pitch, c) = (0.5, point([1.0, 0.0, 0.0]));
+    let twist = LieAlgebra::_from_raw([0.0, 0.0, 1.0, 0.0, -1.0, pitch].into());
+    let body = Kinematics::<Motion3D>::new(EuclideanSpace::<U3>::reference_frame(), twist);
+
+    let screw = Motion3D::from_screw(&c, &vector([0.0, 0.0, 1.0]), 2.0, pitch);
+    let expected = EuclideanSpace::<U3>::reference_frame().act(&screw);
+    let stepped = (0..20).fold(body, |body, _| body.advance(0.1));
+    for local in [[0.0, 0.0, 0.0], [1.0, 2.0, 3.0]] {
+        assert_vector_close(&place(&stepped.point, local), &place(&expected, local));
+    }
+
+    // The same motion in the body convention, starting from a displaced pose
+    let start = EuclideanSpace::<U3>::reference_frame().act(&Motion3D::look_at(
+        &point([3.0, 0.0, 1.0]),
+        &point([3.0, 5.0, 1.0]),
+        &vector([0.0, 0.0, 1.0]),
+    ));
+    // Body x is reference y and body z is reference z, so the axis passes through the body
+    // point (0, 2, 0) and the body origin moves with -ω × r
+    let body_twist = LieAlgebra::_from_raw([0.0, 0.0, 1.0, 2.0, 0.0, pitch].into());
+    let body =
+        Kinematics::<Motion3D, Body>::with_convention(start.clone(), body_twist).advance(2.0);
+    let expected = start.act(&screw);
+    for local in [[0.0, 0.0, 0.0], [1.0, 2.0, 3.0]] {
+        assert_vector_close(&place(&body.point, local), &place(&expected, local));
+    }
+}
+
+fn twist(raw: [f64; 6]) -> LieAlgebra<Motion3D> {
+    LieAlgebra::_from_raw(Vector6::from(raw))
+}
+
+#[test]
+fn test_relative_kinematics() {
+    // Seen from a carousel turning at 2 rad/s, a point at rest 1 m from its axis goes round the
+    // other way and accelerates towards the axis
+    let carousel = Kinematics::new(
+        EuclideanSpace::<U3>::reference_frame(),
+        twist([0.0, 0.0, 2.0, 0.0, 0.0, 0.0]),
+    );
+    let at_rest = Kinematics::stationary(
+        EuclideanSpace::<U3>::reference_frame()
+            .act(&Motion3D::from_translation(&vector([1.0, 0.0, 0.0]))),
+    );
+    for t in [0.0, 0.4] {
+        let seen = carousel.advance(t).relative_frame(&at_rest);
+        let position = seen.origin._from_origin.clone();
+        assert_vector_close(&seen.angular_velocity, &vector([0.0, 0.0, -2.0]));
+        assert_vector_close(&seen.acceleration, &(&position * -4.0));
+    }
+
+    // A point moving in a straight line through the axis has the Coriolis acceleration
+    let passing = Kinematics::new(
+        EuclideanSpace::<U3>::reference_frame(),
+        twist([0.0, 0.0, 0.0, 3.0, 0.0, 0.0]),
+    );
+    let seen = carousel.relative_frame(&passing);
+    assert_vector_close(&seen.velocity, &vector([3.0, 0.0, 0.0]));
+    assert_vector_close(&seen.acceleration, &vector([0.0, -12.0, 0.0]));
+
+    // Rates of the relative velocity and position seen from either body, by finite differences
+    let a = Kinematics::new(
+        EuclideanSpace::<U3>::reference_frame(),
+        twist([0.3, -0.5, 1.2, 0.7, 0.1, -0.4]),
+    );
+    let b = Kinematics::new(
+        EuclideanSpace::<U3>::reference_frame().act(&Motion3D::from_screw(
+            &point([1.0, 2.0, 0.0]),
+            &vector([1.0, 1.0, 1.0]),
+            0.8,
+            0.2,
+        )),
+        twist([-0.6, 0.2, 0.4, 0.0, 1.5, 0.3]),
+    );
+    let acceleration = a.relative_acceleration(&b);
+    let h = 1e-4;
+    for in_b in [false, true] {
+        let velocity = |dt: f64| {
+            let (a, b) = (a.advance(dt), b.advance(dt));
+            let frame = if in_b { &b.point } else { &a.point };
+            *a.relative_velocity(&b).in_frame(frame)._get_raw()
+        };
+        let rate = (velocity(h) - velocity(-h)) / (2.0 * h);
+        let expected = acceleration.in_frame(if in_b { &b.point } else { &a.point });
+        assert!((rate - expected._get_raw()).norm() < 1e-6);
+    }
+
+    let h = 1e-3;
+    let position = |dt: f64| {
+        let frame = a.advance(dt).relative_pose(&b.advance(dt));
+        *frame.origin()._from_origin._get_raw()
+    };
+    let second = (position(h) - position(0.0) * 2.0 + position(-h)) / (h * h);
+    let seen = a.relative_frame(&b);
+    assert!((second - seen.acceleration._get_raw()).norm() < 1e-5);
+}
+
+#[test]
+fn test_accelerations() {
+    let state = Kinematics::new(
+        EuclideanSpace::<U3>::reference_frame().act(&Motion3D::from_screw(
+            &point([0.0, 1.0, 2.0]),
+            &vector([0.0, 1.0, 0.0]),
+            1.1,
+            0.3,
+        )),
+        twist([0.2, 0.0, 1.0, -0.5, 0.4, 0.0]),
+    )
+    .with_acceleration(twist([0.0, 0.3, -0.2, 1.0, 0.0, 0.5]));
+
+    // The body acceleration is the rate of the body velocity, and both conventions move alike
+    let body = state.to_convention::<Body>();
+    assert!((body.velocity._get_raw() - state.body_velocity()._get_raw()).norm() < 1e-12);
+    let h = 1e-4;
+    let rate =
+        (*body.advance(h).velocity._get_raw() - body.advance(-h).velocity._get_raw()) / (2.0 * h);
+    let expected = state.body_acceleration();
+    let from_space = (state.advance(h).body_velocity()._get_raw()
+        - state.advance(-h).body_velocity()._get_raw())
+        / (2.0 * h);
+    assert!((rate - expected._get_raw()).norm() < 1e-6);
+    assert!((from_space - expected._get_raw()).norm() < 1e-6);
+    let back = body.to_convention::<Space>();
+    assert!((back.acceleration._get_raw() - state.acceleration._get_raw()).norm() < 1e-12);
+
+    // Derivative of the adjoint
+    let fixed = twist([1.0, -2.0, 0.5, 0.0, 0.3, 1.0]);
+    let rate = (fixed.in_frame(&state.advance(h).point)._get_raw()
+        - fixed.in_frame(&state.advance(-h).point)._get_raw())
+        / (2.0 * h);
+    assert!((rate - state.adjoint_rate(&fixed)._get_raw()).norm() < 1e-6);
+
+    // A uniform spin has no space acceleration, but its points accelerate towards the axis
+    let spin = Kinematics::new(
+        EuclideanSpace::<U3>::reference_frame(),
+        twist([0.0, 0.0, 3.0, 0.0, 0.0, 0.0]),
+    );
+    assert_eq!(spin.space_acceleration()._get_raw().norm(), 0.0);
+    let rim = point([2.0, 0.0, 0.0]);
+    assert_vector_close(&spin.point_velocity(&rim), &vector([0.0, 6.0, 0.0]));
+    assert_vector_close(&spin.point_acceleration(&rim), &vector([-18.0, 0.0, 0.0]));
+
+    // Classical accelerations of a moving frame survive the round trip through the space ones
+    let frame = MovingFrame::from_kinematics(&state);
+    let again = MovingFrame::from_kinematics(&frame.kinematics());
+    assert_vector_close(&again.acceleration, &frame.acceleration);
+    assert_vector_close(&again.velocity, &frame.velocity);
+    assert_vector_close(
+        &frame.acceleration,
+        &state.point_acceleration(&state.point.origin()),
+    );
+}
@@ -0,0 +1,95 @@
+use geometrica::Error;
+use geometrica::euclidean::*;
+use geometrica::linear::LinearMap;
+use geometrica::linear::basis::{LinearBasis, OrthonormalLinearBasis};
+use geometrica::linear::space::LinearSpace;
+
+mod common;
+
+use common::{V3, assert_close, reference, rotated};
+
+type V2 = Vector<U2>;
+
+fn plane() -> OrthonormalLinearBasis<V2> {
+    OrthonormalLinearBasis {
+        _from_origin: Rotation2D::identity(),
+    }
+}
+
+#[test]
+fn test_composition_and_inverse() {
+    let (e2, e3) = (plane(), reference());
+    let embed = LinearMap::<V2, V3>::from_local(&e2, &e3, &[1.0, 0.0, 0.0, 1.0, 1.0, 1.0]);
+    let project = LinearMap::<V3, V2>::from_local(&e3, &e2, &[1.0, 0.0, 0.0, 0.0, 2.0, 0.0]);
+
+    let v = e2.from_local(&[3.0, -1.0].into());
+    assert_close(&e3.to_local(&embed.apply(&v)), &[3.0, -1.0, 2.0]);
+
+    let composed = project.compose(&embed);
+    assert_close(&composed.to_local(&e2, &e2), &[1.0, 0.0, 0.0, 2.0]);
+    assert_close(
+        &composed.inverse().unwrap().to_local(&e2, &e2),
+        &[1.0, 0.0, 0.0, 0.5],
+    );
+    assert_close(
+        &composed
+            .compose(&composed.inverse().unwrap())
+            .to_local(&e2, &e2),
+        &LinearMap::<V2, V2>::identity().to_local(&e2, &e2),
+    );
+
+    let singular = embed.compose(&project);
+    assert!(singular.inverse().is_none());
+    assert!(singular.to_group().is_none());
+
+    assert_eq!(
+        LinearMap::<V2, V3>::try_from_local(&e2, &e3, &[1.0, 0.0, 0.0, 1.0]).unwrap_err(),
+        Error::DimensionMismatch
+    );
+}
+
+#[test]
+fn test_transpose_and_adjoint() {
+    let (e2, e3) = (plane(), reference());
+    let map = LinearMap::<V2, V3>::from_local(&e2, &e3, &[1.0, 2.0, 0.0, -1.0, 3.0, 4.0]);
+    let v = e2.from_local(&[2.0, 1.0].into());
+    let alpha = Covector::<U3>::_from_raw([1.0, -2.0, 0.5].into());
+
+    // (A^T α)(v) = α(A v)
+    let pulled = map.transpose().apply(&alpha);
+    assert_close(&[v.pair_with(&pulled)], &[map.apply(&v).pair_with(&alpha)]);
+
+    // <A v, w> = <v, A* w>
+    let w = e3.from_local(&[0.0, 1.0, -1.0].into());
+    let adjoint = map.adjoint();
+    assert_close(&[map.apply(&v).dot(&w)], &[v.dot(&adjoint.apply(&w))]);
+    assert_close(
+        &adjoint.to_local(&e3, &e2),
+        &[1.0, 0.0, 3.0, 2.0, -1.0, 4.0],
+    );
+}
+
+#[test]
+fn test_components_in_bases() {
+    let (e, b) = (reference(), rotated());
+    let map =
+        LinearMap::<V3, V3>::from_local(&e, &e, &[1.0, 2.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0, 4.0]);
+    assert_close(
+        &map.to_local(&b, &b),
+        &[3.0, 0.0, 0.0, -2.0, 1.0, 0.0, 0.0, 0.0, 4.0],
+    );
+
+    let round_trip = LinearMap::<V3, V3>::from_local(&b, &e, &map.to_local(&b, &e));
+    assert_close(&round_trip.to_local(&e, &e), &map.to_local(&e, &e));
+
+    // A general linear basis obtained from an invertible map
+    let skew = LinearBasis {
+        _from_origin: map.to_group().unwrap(),
+    };
+    let v = e.from_local(&[1.0, 1.0, 1.0].into());
+    assert_close(&skew.to_local(&v), &e.to_local(&map.apply(&v)));
+    assert_close(
+        &LinearMap::<V3, V3>::from(map.to_group().unwrap()).to_local(&e, &e),
+        &map.to_local(&e, &e),
+    );
+}
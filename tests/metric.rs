@@ -0,0 +1,145 @@
+use geometrica::Error;
+use geometrica::euclidean::*;
+use geometrica::linear::basis::LinearBasis;
+use geometrica::linear::metric::MinkowskiVector;
+use geometrica::linear::{LinearMap, Metric, MetricCovector, MetricVector, Tensor};
+use nalgebra::{Matrix2, U4};
+
+mod common;
+
+use common::assert_close;
+
+// Triangular lattice, with reference basis vectors of unit length at 60 degrees
+#[derive(Clone, Debug)]
+struct Hexagonal;
+
+impl Metric<U2, f64> for Hexagonal {
+    fn gram() -> Matrix2<f64> {
+        Matrix2::new(1.0, 0.5, 0.5, 1.0)
+    }
+}
+
+// Degenerate form, under which the second reference vector has no length
+#[derive(Clone, Debug)]
+struct Cylinder;
+
+impl Metric<U2, f64> for Cylinder {
+    fn gram() -> Matrix2<f64> {
+        Matrix2::new(1.0, 0.0, 0.0, 0.0)
+    }
+}
+
+type Lattice = MetricVector<U2, Hexagonal>;
+type Spacetime = MinkowskiVector<U4>;
+
+fn lattice(a: f64, b: f64) -> Lattice {
+    Lattice::_from_raw([a, b].into())
+}
+
+fn event(t: f64, x: f64, y: f64, z: f64) -> Spacetime {
+    Spacetime::_from_raw([t, x, y, z].into())
+}
+
+fn reference<V: LinearSpace<Field = f64, Dim = U4>>() -> LinearBasis<V> {
+    LinearBasis {
+        _from_origin: LinearMap::<V, V>::identity().to_group().unwrap(),
+    }
+}
+
+#[test]
+fn test_skewed_lattice() {
+    let (a, b) = (lattice(1.0, 0.0), lattice(0.0, 1.0));
+    assert_close(
+        &[a.dot(&b), (lattice(1.0, -1.0)).norm_squared()],
+        &[0.5, 1.0],
+    );
+
+    // Flat and sharp are mutually inverse, and pairing with the flat is the inner product
+    let v = lattice(2.0, -1.0);
+    let flat = v.flat();
+    assert_close(flat._get_raw().as_slice(), &[1.5, 0.0]);
+    assert_close(&[b.pair_with(&flat)], &[b.dot(&v)]);
+    assert_close(
+        Lattice::sharp(&flat).unwrap()._get_raw().as_slice(),
+        &[2.0, -1.0],
+    );
+
+    // The sharp of the dual basis is the reciprocal lattice
+    let dual = MetricCovector::<U2, Hexagonal>::_from_raw([1.0, 0.0].into());
+    assert_close(&[a.pair_with(&dual), b.pair_with(&dual)], &[1.0, 0.0]);
+    let reciprocal = Lattice::sharp(&dual).unwrap();
+    assert_close(&[reciprocal.dot(&a), reciprocal.dot(&b)], &[1.0, 0.0]);
+}
+
+#[test]
+fn test_degenerate_metric() {
+    let covector = MetricCovector::<U2, Cylinder>::_from_raw([1.0, 0.0].into());
+    assert_eq!(
+        MetricVector::<U2, Cylinder>::sharp(&covector).unwrap_err(),
+        Error::Singular
+    );
+
+    type Degenerate = MetricVector<U2, Cylinder>;
+    assert_eq!(
+        Tensor::<Degenerate, 2, 0>::try_inverse_metric().unwrap_err(),
+        Error::Singular
+    );
+    let metric = Tensor::<Degenerate, 0, 2>::metric();
+    assert_eq!(metric.try_raise::<1, 1>(0).unwrap_err(), Error::Singular);
+    let map = LinearMap::<Degenerate, Degenerate>::identity();
+    assert_eq!(map.try_adjoint().unwrap_err(), Error::Singular);
+}
+
+#[test]
+fn test_minkowski_space() {
+    let light = event(1.0, 1.0, 0.0, 0.0);
+    let time = event(1.0, 0.0, 0.0, 0.0);
+    let space = event(0.0, 0.0, 2.0, 0.0);
+    assert_close(
+        &[
+            light.norm_squared(),
+            time.norm_squared(),
+            space.norm_squared(),
+        ],
+        &[0.0, -1.0, 4.0],
+    );
+    assert_close(light.flat()._get_raw().as_slice(), &[-1.0, 1.0, 0.0, 0.0]);
+    assert_close(
+        Spacetime::sharp(&light.flat())
+            .unwrap()
+            ._get_raw()
+            .as_slice(),
+        &[1.0, 1.0, 0.0, 0.0],
+    );
+
+    let e = reference::<Spacetime>();
+    let metric = Tensor::<Spacetime, 0, 2>::metric().to_local(&e);
+    assert_close(&metric[..5], &[-1.0, 0.0, 0.0, 0.0, 0.0]);
+    assert_close(&[metric[5], metric[10], metric[15]], &[1.0, 1.0, 1.0]);
+
+    let vector = Tensor::from_vector(&time);
+    let lowered: Tensor<Spacetime, 0, 1> = vector.lower(0);
+    assert_close(&lowered.to_local(&e), &[-1.0, 0.0, 0.0, 0.0]);
+}
+
+#[test]
+fn test_boost_adjoint() {
+    // The Minkowski adjoint of a boost is the inverse boost, not the transpose
+    let e = reference::<Spacetime>();
+    let (c, s) = (1.25, 0.75);
+    let boost = LinearMap::<Spacetime, Spacetime>::from_local(
+        &e,
+        &e,
+        &[
+            c, s, 0.0, 0.0, s, c, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+        ],
+    );
+    let inverse = boost.inverse().unwrap();
+    assert_close(
+        &boost.try_adjoint().unwrap().to_local(&e, &e),
+        &inverse.to_local(&e, &e),
+    );
+
+    let v = event(2.0, 1.0, 0.5, 0.0);
+    assert_close(&[boost.apply(&v).norm_squared()], &[v.norm_squared()]);
+}
@@ -0,0 +1,143 @@
+use std::f64::consts::FRAC_PI_2;
+
+use geometrica::autodiff::Dual;
+use geometrica::euclidean::*;
+use geometrica::kinematics::{FrameTrajectory, MovingFrame};
+use geometrica::lie::GroupAction;
+use geometrica::linear::basis::OrthonormalLinearBasis;
+use nalgebra::ComplexField;
+
+mod common;
+
+use common::{V3, assert_vector_close, point, vector};
+
+// Axes turned by `angle` about z from the reference axes
+fn axes(angle: f64) -> OrthonormalLinearBasis<V3> {
+    OrthonormalLinearBasis {
+        _from_origin: Rotation3D::from_axis_angle(&vector([0.0, 0.0, 1.0]), -angle),
+    }
+}
+
+// Turntable spinning at 1 rad/s about z, centred at the origin
+fn turntable(t: f64) -> MovingFrame {
+    MovingFrame::rotating(point([0.0, 0.0, 0.0]), axes(t), vector([0.0, 0.0, 1.0]))
+}
+
+#[test]
+fn test_transport_theorem() {
+    let frame = turntable(0.0);
+    let p = point([1.0, 0.0, 0.0]);
+
+    // A point carried by the turntable is at rest in it
+    let carried = vector([0.0, 1.0, 0.0]);
+    assert_vector_close(
+        &frame.velocity_in_frame(&p, &carried),
+        &vector([0.0, 0.0, 0.0]),
+    );
+    assert_vector_close(
+        &frame.acceleration_in_frame(&p, &carried, &vector([-1.0, 0.0, 0.0])),
+        &vector([0.0, 0.0, 0.0]),
+    );
+
+    // A point at rest in the reference circles backwards on the turntable, pulled inwards by
+    // the centrifugal and Coriolis terms together
+    let zero = vector([0.0, 0.0, 0.0]);
+    let velocity = frame.velocity_in_frame(&p, &zero);
+    assert_vector_close(&velocity, &vector([0.0, -1.0, 0.0]));
+    let acceleration = frame.acceleration_in_frame(&p, &zero, &zero);
+    assert_vector_close(&acceleration, &vector([-1.0, 0.0, 0.0]));
+    assert_vector_close(
+        &frame.velocity_in_reference(&p, &velocity),
+        &vector([0.0, 0.0, 0.0]),
+    );
+    assert_vector_close(
+        &frame.acceleration_in_reference(&p, &velocity, &acceleration),
+        &vector([0.0, 0.0, 0.0]),
+    );
+
+    // Vector quantities only pick up ω × q
+    let q = vector([0.0, 2.0, 5.0]);
+    assert_vector_close(&frame.rate_in_frame(&q, &zero), &vector([2.0, 0.0, 0.0]));
+    assert_vector_close(
+        &frame.rate_in_reference(&q, &vector([2.0, 0.0, 0.0])),
+        &vector([0.0, 0.0, 0.0]),
+    );
+
+    // Translating and spinning up frames
+    let frame = MovingFrame {
+        velocity: vector([0.0, 0.0, 3.0]),
+        acceleration: vector([0.0, 0.0, 1.0]),
+        angular_acceleration: vector([0.0, 0.0, 2.0]),
+        ..turntable(0.0)
+    };
+    assert_vector_close(
+        &frame.velocity_in_frame(&p, &zero),
+        &vector([0.0, -1.0, -3.0]),
+    );
+    assert_vector_close(
+        &frame.acceleration_in_frame(&p, &carried, &vector([-1.0, 0.0, 0.0])),
+        &vector([0.0, -2.0, -1.0]),
+    );
+}
+
+#[test]
+fn test_rates_of_functions_of_time() {
+    // The first axis of the turntable, which turns with it
+    let axis =
+        |t: Dual| Vector::<U3, Dual>::_from_raw([t.cos(), t.sin(), Dual::constant(0.0)].into());
+    assert_vector_close(&turntable.rate_of(axis, 0.7), &vector([0.0, 0.0, 0.0]));
+    assert_vector_close(
+        &(|t: f64| MovingFrame::stationary(point([0.0; 3]), axes(t))).rate_of(axis, 0.0),
+        &vector([0.0, 1.0, 0.0]),
+    );
+
+    // A point moving outwards along that axis, seen from the turntable
+    let t = FRAC_PI_2;
+    let position = |t: Dual| EuclideanSpace {
+        _from_origin: Vector::<U3, Dual>::_from_raw([t * t.cos(), t * t.sin(), t].into()),
+    };
+    let velocity = turntable.velocity_of(position, t);
+    assert_vector_close(&velocity, &vector([0.0, 1.0, 1.0]));
+    let acceleration = turntable.acceleration_of(
+        |t| EuclideanSpace {
+            _from_origin: Vector::_from_raw([t * t.cos(), t * t.sin(), t].into()),
+        },
+        t,
+    );
+    assert_vector_close(&acceleration, &vector([0.0, 0.0, 0.0]));
+}
+
+#[test]
+fn test_frame_pose() {
+    let frame = MovingFrame::stationary(point([1.0, 2.0, 3.0]), axes(FRAC_PI_2));
+    let pose = frame.pose();
+    let local =
+        GroupAction::<EuclideanSpace<U3>>::act_on(&pose._from_origin, &point([1.0, 3.0, 3.0]));
+    assert_vector_close(&local._from_origin, &vector([1.0, 0.0, 0.0]));
+}
+
+#[test]
+fn test_kinematics_away_from_the_reference_origin() {
+    // A turntable centred at (2, 0, 0), carried along y
+    let frame = MovingFrame {
+        velocity: vector([0.0, 3.0, 0.0]),
+        ..MovingFrame::rotating(point([2.0, 0.0, 0.0]), axes(0.0), vector([0.0, 0.0, 1.0]))
+    };
+    let kinematics = frame.kinematics();
+
+    // The linear part of the space velocity is that of the point of the frame passing the
+    // reference origin, V - ω × o
+    let raw = kinematics.velocity._get_raw();
+    assert_vector_close(&kinematics.angular_velocity(), &vector([0.0, 0.0, 1.0]));
+    assert_vector_close(&vector([raw[3], raw[4], raw[5]]), &vector([0.0, 1.0, 0.0]));
+
+    // and the origin itself still moves with V
+    assert_vector_close(
+        &kinematics.point_velocity(&point([2.0, 0.0, 0.0])),
+        &vector([0.0, 3.0, 0.0]),
+    );
+    assert_vector_close(
+        &kinematics.point_velocity(&point([2.0, 1.0, 0.0])),
+        &vector([-1.0, 3.0, 0.0]),
+    );
+}
@@ -0,0 +1,36 @@
+use geometrica::euclidean::*;
+
+mod common;
+
+use common::{assert_vector_close, point, vector};
+
+#[test]
+fn test_vector_ops() {
+    let (a, b) = (vector([1.0, 2.0, 3.0]), vector([0.5, -1.0, 2.0]));
+    assert_vector_close(&(&a + &b), &vector([1.5, 1.0, 5.0]));
+    assert_vector_close(&(&a - &b), &vector([0.5, 3.0, 1.0]));
+    assert_vector_close(&-&a, &vector([-1.0, -2.0, -3.0]));
+    assert_vector_close(&(&a * 2.0), &vector([2.0, 4.0, 6.0]));
+    assert_vector_close(
+        &(a.clone() * 2.0 - b.clone() + -a.clone()),
+        &vector([0.5, 3.0, 1.0]),
+    );
+
+    let alpha = Covector::<U3>::_from_raw([1.0, 0.0, -1.0].into());
+    let beta = &(&alpha * 3.0) - &alpha;
+    assert_eq!(b.pair_with(&beta), -3.0);
+    assert_eq!(b.pair_with(&-beta), 3.0);
+}
+
+#[test]
+fn test_affine_ops() {
+    let (p, q) = (point([1.0, 1.0, 1.0]), point([4.0, -1.0, 2.0]));
+    let v = &q - &p;
+    assert_vector_close(&v, &vector([3.0, -2.0, 1.0]));
+    assert_vector_close(&((&p + &v) - q.clone()), &vector([0.0, 0.0, 0.0]));
+    assert_vector_close(&(&(&q - &v) - &p), &vector([0.0, 0.0, 0.0]));
+
+    // Midpoint as an affine combination, p + (q - p) / 2
+    let midpoint = p.clone() + (q - p) * 0.5;
+    assert_vector_close(&midpoint._from_origin, &vector([2.5, 0.0, 1.5]));
+}
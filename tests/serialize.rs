@@ -0,0 +1,167 @@
+use std::f64::consts::FRAC_PI_2;
+
+use geometrica::astrodynamics::*;
+use geometrica::euclidean::*;
+use geometrica::kinematics::Kinematics;
+use geometrica::lie::{GroupAction, LieAlgebra};
+use geometrica::linear::basis::{LinearBasis, OrthonormalLinearBasis};
+use geometrica::serialize::{DeserializeInFrame, InFrame, WithFrame};
+use serde::de::DeserializeSeed;
+
+mod common;
+
+use common::{V3, reference, vector};
+
+// Basis rotated by a quarter turn about z, so that x' = y and y' = -x
+fn rotated() -> OrthonormalLinearBasis<V3> {
+    OrthonormalLinearBasis {
+        _from_origin: Rotation3D::from_axis_angle(&vector([0.0, 0.0, 1.0]), -FRAC_PI_2),
+    }
+}
+
+fn to_json<C, T: geometrica::serialize::SerializeInFrame<C>>(frame: &C, value: &T) -> String {
+    serde_json::to_string(&WithFrame::new(frame, value)).unwrap()
+}
+
+fn from_json<'de, C, T: DeserializeInFrame<'de, C>>(frame: &C, json: &'de str) -> T {
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    InFrame::new(frame).deserialize(&mut deserializer).unwrap()
+}
+
+fn parse(json: &str) -> Vec<f64> {
+    serde_json::from_str::<Vec<f64>>(json)
+        .unwrap()
+        .iter()
+        .map(|x| (x * 1e9).round() / 1e9)
+        .collect()
+}
+
+#[test]
+fn test_vectors_in_frames() {
+    let v = vector([1.0, 2.0, 3.0]);
+    assert_eq!(to_json(&reference(), &v), "[1.0,2.0,3.0]");
+    assert_eq!(parse(&to_json(&rotated(), &v)), vec![2.0, -1.0, 3.0]);
+
+    let back: V3 = from_json(&rotated(), "[2.0,-1.0,3.0]");
+    assert!((back._get_raw() - v._get_raw()).norm() < 1e-12);
+
+    // Covectors transform dually, so that pairings are the same in every frame
+    let basis = LinearBasis::try_from_vectors(&[
+        vector([2.0, 0.0, 0.0]),
+        vector([1.0, 1.0, 0.0]),
+        vector([0.0, 0.0, 1.0]),
+    ])
+    .unwrap();
+    let alpha = Covector::<U3>::_from_raw([1.0, -1.0, 2.0].into());
+    assert_eq!(to_json(&basis, &alpha), "[2.0,0.0,2.0]");
+    assert_eq!(to_json(&basis, &v), "[-0.5,2.0,3.0]");
+    let back: Covector<U3> = from_json(&basis, "[2.0,0.0,2.0]");
+    assert_eq!(back._get_raw(), alpha._get_raw());
+
+    let mut deserializer = serde_json::Deserializer::from_str("[1.0,2.0]");
+    assert!(
+        InFrame::<_, V3>::new(&basis)
+            .deserialize(&mut deserializer)
+            .is_err()
+    );
+}
+
+#[test]
+fn test_points_in_charts() {
+    let ecef = Kinematics::stationary(ECI::as_reference());
+    let chart = GeodeticChart::wgs84(&ecef);
+    let point: EuclideanSpace<U3> = from_json(&chart, "[0.3,-2.0,1200.0]");
+    let json = to_json(&chart, &point);
+    let geodetic = parse(&json);
+    assert!((geodetic[0] - 0.3).abs() < 1e-9 && (geodetic[1] + 2.0).abs() < 1e-9);
+    assert!((geodetic[2] - 1200.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_rotations_and_motions() {
+    let rotation = Rotation3D::from_axis_angle(&vector([1.0, 0.0, 0.0]), FRAC_PI_2);
+    let json = to_json(&reference(), &rotation);
+    assert_eq!(
+        parse(&json),
+        vec![1.0, 0.0, 0.0, 0.0, 0.0, -1.0, 0.0, 1.0, 0.0]
+    );
+    // The same rotation about x is a rotation about -y' in the rotated basis
+    assert_eq!(
+        parse(&to_json(&rotated(), &rotation)),
+        vec![0.0, 0.0, -1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 0.0]
+    );
+    let back: Rotation3D = from_json(&rotated(), &to_json(&rotated(), &rotation));
+    let w = vector([0.3, -1.0, 2.0]);
+    assert!((back.act_on(&w)._get_raw() - rotation.act_on(&w)._get_raw()).norm() < 1e-12);
+
+    // Matrices which are not rotations are rejected
+    let mut deserializer = serde_json::Deserializer::from_str("[1,0,0,0,1,0,0,0,-1]");
+    assert!(
+        InFrame::<_, Rotation3D>::new(&reference())
+            .deserialize(&mut deserializer)
+            .is_err()
+    );
+
+    // A quarter turn about z followed by a unit translation along x
+    let json = r#"{"rotation":[0,-1,0,1,0,0,0,0,1],"translation":[1,0,0]}"#;
+    let motion: Motion3D = from_json(&reference(), json);
+    let p = GroupAction::<EuclideanSpace<U3>>::act_on(
+        &motion,
+        &EuclideanSpace {
+            _from_origin: vector([1.0, 0.0, 0.0]),
+        },
+    );
+    assert!((p._from_origin._get_raw() - vector([1.0, 1.0, 0.0])._get_raw()).norm() < 1e-12);
+    let local: serde_json::Value = serde_json::from_str(&to_json(&rotated(), &motion)).unwrap();
+    assert_eq!(
+        parse(&local["translation"].to_string()),
+        vec![0.0, -1.0, 0.0]
+    );
+    let back: Motion3D = from_json(&rotated(), &local.to_string());
+    let back: serde_json::Value = serde_json::from_str(&to_json(&reference(), &back)).unwrap();
+    assert_eq!(
+        parse(&back["rotation"].to_string()),
+        vec![0.0, -1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0]
+    );
+    assert_eq!(parse(&back["translation"].to_string()), vec![1.0, 0.0, 0.0]);
+}
+
+#[test]
+fn test_kinematics() {
+    let json = r#"{
+        "pose": {"rotation": [1,0,0,0,1,0,0,0,1], "translation": [0,0,7]},
+        "angular_velocity": [0,0,0.5],
+        "linear_velocity": [1,2,0]
+    }"#;
+    let state: Kinematics<Motion3D> = from_json(&reference(), json);
+    let local: serde_json::Value = serde_json::from_str(&to_json(&rotated(), &state)).unwrap();
+    assert_eq!(
+        parse(&local["pose"]["translation"].to_string()),
+        vec![0.0, 0.0, 7.0]
+    );
+    assert_eq!(
+        parse(&local["angular_velocity"].to_string()),
+        vec![0.0, 0.0, 0.5]
+    );
+    assert_eq!(
+        parse(&local["linear_velocity"].to_string()),
+        vec![2.0, -1.0, 0.0]
+    );
+
+    let back: Kinematics<Motion3D> = from_json(&rotated(), &local.to_string());
+    assert!((back.velocity._get_raw() - state.velocity._get_raw()).norm() < 1e-12);
+
+    // Accelerations are only written when there are any
+    assert!(local.get("angular_acceleration").is_none());
+    let accelerating =
+        state.with_acceleration(LieAlgebra::_from_raw([0.0, 0.0, 1.0, 0.0, 3.0, 0.0].into()));
+    let local: serde_json::Value =
+        serde_json::from_str(&to_json(&rotated(), &accelerating)).unwrap();
+    assert_eq!(
+        parse(&local["linear_acceleration"].to_string()),
+        vec![3.0, 0.0, 0.0]
+    );
+    let back: Kinematics<Motion3D> = from_json(&rotated(), &local.to_string());
+    let difference = back.acceleration._get_raw() - accelerating.acceleration._get_raw();
+    assert!(difference.norm() < 1e-12);
+}
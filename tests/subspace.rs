@@ -0,0 +1,131 @@
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+use geometrica::Error;
+use geometrica::euclidean::*;
+use geometrica::lie::GroupAction;
+use geometrica::linear::{AffineSubspace, LinearSubspace};
+
+mod common;
+
+use common::{V3, assert_vector_close, point, vector};
+
+fn covector(components: [f64; 3]) -> Covector<U3> {
+    Covector::_from_raw(components.into())
+}
+
+#[test]
+fn test_linear_subspaces() {
+    let xy = LinearSubspace::span(&[
+        vector([1.0, 1.0, 0.0]),
+        vector([2.0, 2.0, 0.0]),
+        vector([0.0, 3.0, 0.0]),
+    ]);
+    assert_eq!(xy.dim(), 2);
+    let v = vector([1.0, 2.0, 3.0]);
+    assert_vector_close(&xy.project(&v), &vector([1.0, 2.0, 0.0]));
+    assert_vector_close(&xy.reject(&v), &vector([0.0, 0.0, 3.0]));
+    assert!((xy.distance(&v) - 3.0).abs() < 1e-12);
+    assert!(xy.contains(&vector([-4.0, 5.0, 0.0])));
+    assert!(!xy.contains(&v));
+
+    // The plane with normal dz is the same subspace
+    let normal = LinearSubspace::<V3>::from_normals(&[covector([0.0, 0.0, 2.0])]);
+    assert_eq!(normal.dim(), 2);
+    assert!(xy.principal_angles(&normal).iter().all(|a| a.abs() < 1e-6));
+    assert_eq!(xy.complement().dim(), 1);
+    assert_eq!(LinearSubspace::<V3>::full().dim(), 3);
+
+    assert!(
+        LinearSubspace::try_from_basis(&[vector([1.0, 0.0, 0.0]), vector([2.0, 0.0, 0.0])])
+            .is_err()
+    );
+    assert!(LinearSubspace::try_from_basis(&[vector([0.0, 0.0, 0.0])]).is_err());
+}
+
+#[test]
+fn test_intersections_and_angles() {
+    let xy = LinearSubspace::span(&[vector([1.0, 0.0, 0.0]), vector([0.0, 1.0, 0.0])]);
+    let tilted = LinearSubspace::span(&[vector([1.0, 0.0, 0.0]), vector([0.0, 1.0, 1.0])]);
+    let line = xy.intersection(&tilted);
+    assert_eq!(line.dim(), 1);
+    assert!(line.contains(&vector([3.0, 0.0, 0.0])));
+    assert!((xy.angle(&tilted) - FRAC_PI_4).abs() < 1e-12);
+
+    let z = LinearSubspace::span(&[vector([0.0, 0.0, 1.0])]);
+    assert!((z.angle(&xy) - FRAC_PI_2).abs() < 1e-12);
+    assert_eq!(z.intersection(&xy).dim(), 0);
+}
+
+#[test]
+fn test_affine_subspaces() {
+    let plane =
+        AffineSubspace::hyperplane(point([0.0, 0.0, 1.0]), &covector([0.0, 0.0, 1.0])).unwrap();
+    let p = point([2.0, -1.0, 4.0]);
+    assert_vector_close(&plane.project(&p)._from_origin, &vector([2.0, -1.0, 1.0]));
+    assert!((plane.distance(&p) - 3.0).abs() < 1e-12);
+    assert!(plane.contains(&point([5.0, 7.0, 1.0])));
+
+    // A line crossing the plane meets it in a single point
+    let line = AffineSubspace::line(point([1.0, 1.0, 0.0]), &vector([1.0, 0.0, 1.0])).unwrap();
+    let meet = line.intersection(&plane).unwrap();
+    assert_eq!(meet.dim(), 0);
+    assert_vector_close(&meet.point()._from_origin, &vector([2.0, 1.0, 1.0]));
+    assert!((line.angle(&plane) - FRAC_PI_4).abs() < 1e-12);
+
+    // Parallel subspaces do not meet, and skew lines neither
+    let parallel =
+        AffineSubspace::hyperplane(point([0.0, 0.0, 2.0]), &covector([0.0, 0.0, 1.0])).unwrap();
+    assert!(plane.intersection(&parallel).is_none());
+    let skew = AffineSubspace::line(point([0.0, 0.0, 5.0]), &vector([0.0, 1.0, 0.0])).unwrap();
+    assert!(line.intersection(&skew).is_none());
+
+    let through = AffineSubspace::through(&[
+        point([1.0, 0.0, 1.0]),
+        point([0.0, 1.0, 1.0]),
+        point([2.0, -1.0, 1.0]),
+    ])
+    .unwrap();
+    assert_eq!(through.dim(), 1);
+    assert_eq!(
+        AffineSubspace::<V3>::through(&[]).unwrap_err(),
+        Error::Empty
+    );
+    assert!((through.distance(&point([0.0, 0.0, 1.0])) - 0.5f64.sqrt()).abs() < 1e-12);
+}
+
+#[test]
+fn test_rotated_subspaces() {
+    let rotation = Rotation3D::from_axis_angle(&vector([0.0, 0.0, 1.0]), FRAC_PI_2);
+    let line = AffineSubspace::line(point([1.0, 0.0, 0.0]), &vector([0.0, 0.0, 1.0])).unwrap();
+    let rotated = line.act(&rotation);
+    assert!(rotated.contains(&point([0.0, 1.0, 7.0])));
+    assert!(!rotated.contains(&point([1.0, 0.0, 7.0])));
+
+    // Projections and distances are preserved by rotations
+    let p = point([3.0, 2.0, 1.0]);
+    let q = GroupAction::<EuclideanSpace<U3>>::act_on(&rotation, &p);
+    assert!((line.distance(&p) - rotated.distance(&q)).abs() < 1e-12);
+    let plane = AffineSubspace::through(&[
+        point([1.0, 0.0, 0.0]),
+        point([0.0, 1.0, 0.0]),
+        point([0.0, 0.0, 1.0]),
+    ])
+    .unwrap();
+    assert!((plane.angle(&line) - plane.act(&rotation).angle(&rotated)).abs() < 1e-12);
+}
+
+#[test]
+fn test_moved_subspaces() {
+    // A motion moves the base point of a line, while only its rotation turns the direction
+    let rotation = Rotation3D::from_axis_angle(&vector([0.0, 0.0, 1.0]), FRAC_PI_2);
+    let motion = Motion3D::from_parts(&rotation, &vector([2.0, 0.0, 3.0]));
+    let line = AffineSubspace::line(point([1.0, 0.0, 0.0]), &vector([1.0, 0.0, 0.0])).unwrap();
+    let moved = line.act(&motion);
+    let base = GroupAction::<EuclideanSpace<U3>>::act_on(&motion, &point([1.0, 0.0, 0.0]));
+    assert_vector_close(&base._from_origin, &vector([2.0, 1.0, 3.0]));
+    assert!(moved.contains(&base));
+    assert!(moved.direction().contains(&vector([0.0, 1.0, 0.0])));
+    assert!(!moved.direction().contains(&vector([1.0, 0.0, 0.0])));
+    assert!(moved.contains(&point([2.0, 7.0, 3.0])));
+    assert!(!moved.contains(&point([0.0, 7.0, 0.0])));
+}
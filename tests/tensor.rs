@@ -0,0 +1,86 @@
+use geometrica::euclidean::*;
+use geometrica::linear::Tensor;
+
+mod common;
+
+use common::{V3, assert_close, reference, rotated};
+
+#[test]
+fn test_transformation_law() {
+    let e = reference();
+    let b = rotated();
+
+    let v = Tensor::from_vector(&e.from_local(&[1.0, 2.0, 3.0].into()));
+    assert_close(&v.to_local(&b), &[-2.0, 1.0, 3.0]);
+
+    let operator =
+        Tensor::<V3, 1, 1>::from_local(&e, &[1.0, 2.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0, 4.0]);
+    assert_close(
+        &operator.to_local(&b),
+        &[3.0, 0.0, 0.0, -2.0, 1.0, 0.0, 0.0, 0.0, 4.0],
+    );
+    assert_close(&[operator.trace()], &[8.0]);
+
+    let applied = operator.apply(&v.to_vector());
+    assert_close(&e.to_local(&applied), &[5.0, 6.0, 12.0]);
+    assert_close(&b.to_local(&applied), &[-6.0, 5.0, 12.0]);
+
+    let round_trip = Tensor::<V3, 1, 1>::from_local(&b, &operator.to_local(&b));
+    assert_close(&round_trip.to_local(&e), &operator.to_local(&e));
+}
+
+#[test]
+fn test_products_and_contractions() {
+    let e = reference();
+    let v = Tensor::<V3, 1, 0>::from_local(&e, &[1.0, 2.0, 3.0]);
+    let w = Tensor::<V3, 1, 0>::from_local(&e, &[0.0, 1.0, -1.0]);
+    let alpha = Tensor::<V3, 0, 1>::from_local(&e, &[2.0, 0.0, 1.0]);
+
+    let vw: Tensor<V3, 2, 0> = v.tensor(&w);
+    let a = alpha.to_covector();
+    assert_close(&[vw.evaluate([&a, &a], [])], &[-5.0]);
+
+    let v_alpha: Tensor<V3, 1, 1> = v.tensor(&alpha);
+    assert_close(&[v_alpha.contract::<0, 0>(0, 0).to_scalar()], &[5.0]);
+    assert_close(
+        &e.to_local(&v_alpha.apply(&w.to_vector())),
+        &[-1.0, -2.0, -3.0],
+    );
+
+    let mixed: Tensor<V3, 2, 1> = vw.tensor(&alpha);
+    let contracted: Tensor<V3, 1, 0> = mixed.contract(1, 0);
+    assert_close(&contracted.to_local(&e), &[-1.0, -2.0, -3.0]);
+
+    assert_close(
+        &Tensor::<V3, 1, 1>::identity().to_local(&rotated()),
+        &Tensor::<V3, 1, 1>::identity().to_local(&e),
+    );
+}
+
+#[test]
+fn test_raising_and_lowering() {
+    let e = reference();
+    let b = rotated();
+    let v = Tensor::<V3, 1, 0>::from_local(&e, &[1.0, 2.0, 3.0]);
+
+    let flat: Tensor<V3, 0, 1> = v.lower(0);
+    assert_close(&flat.to_local(&b), &v.to_local(&b));
+    let sharp: Tensor<V3, 1, 0> = flat.raise(0);
+    assert_close(&sharp.to_local(&e), &[1.0, 2.0, 3.0]);
+
+    let metric = Tensor::<V3, 0, 2>::metric();
+    assert_close(
+        &metric.to_local(&b),
+        &Tensor::<V3, 1, 1>::identity().to_local(&b),
+    );
+    let x = v.to_vector();
+    assert_close(&[metric.evaluate([], [&x, &x])], &[14.0]);
+
+    let operator =
+        Tensor::<V3, 1, 1>::from_local(&e, &[0.0, 1.0, 0.0, -1.0, 0.0, 0.0, 0.0, 0.0, 2.0]);
+    let bilinear: Tensor<V3, 0, 2> = operator.lower(0);
+    assert_close(
+        &bilinear.to_local(&e),
+        &[0.0, -1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 2.0],
+    );
+}